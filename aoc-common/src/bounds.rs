@@ -0,0 +1,96 @@
+use crate::point::{Point, Point3};
+
+// Inclusive of both min and max
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3
+}
+
+impl Bounds {
+    pub fn new(min: Point, max: Point) -> Self {
+        Bounds { min, max }
+    }
+
+    // the smallest bounds containing every point, or None if there are no points
+    pub fn from_points<'a, I: IntoIterator<Item = &'a Point>>(points: I) -> Option<Self> {
+        let mut iter = points.into_iter();
+        let first = *iter.next()?;
+        let mut bounds = Bounds { min: first, max: first };
+        for p in iter {
+            bounds.include(p);
+        }
+        Some(bounds)
+    }
+
+    pub fn include(&mut self, p: &Point) {
+        self.min.x = self.min.x.min(p.x);
+        self.min.y = self.min.y.min(p.y);
+        self.max.x = self.max.x.max(p.x);
+        self.max.y = self.max.y.max(p.y);
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    pub fn width(&self) -> isize {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> isize {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn area(&self) -> isize {
+        self.width() * self.height()
+    }
+
+    // row by row, from the top left
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Bounds { min, max } = *self;
+        (min.y..(max.y+1)).flat_map(move |y| (min.x..(max.x+1)).map(move |x| Point { x, y }))
+    }
+}
+
+impl Bounds3 {
+    pub fn new(min: Point3, max: Point3) -> Self {
+        Bounds3 { min, max }
+    }
+
+    pub fn from_points<'a, I: IntoIterator<Item = &'a Point3>>(points: I) -> Option<Self> {
+        let mut iter = points.into_iter();
+        let first = *iter.next()?;
+        let mut bounds = Bounds3 { min: first, max: first };
+        for p in iter {
+            bounds.include(p);
+        }
+        Some(bounds)
+    }
+
+    pub fn include(&mut self, p: &Point3) {
+        self.min.x = self.min.x.min(p.x);
+        self.min.y = self.min.y.min(p.y);
+        self.min.z = self.min.z.min(p.z);
+        self.max.x = self.max.x.max(p.x);
+        self.max.y = self.max.y.max(p.y);
+        self.max.z = self.max.z.max(p.z);
+    }
+
+    pub fn contains(&self, p: &Point3) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x
+            && p.y >= self.min.y && p.y <= self.max.y
+            && p.z >= self.min.z && p.z <= self.max.z
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let Bounds3 { min, max } = *self;
+        (min.x..(max.x+1)).flat_map(move |x| (min.y..(max.y+1)).flat_map(move |y| (min.z..(max.z+1)).map(move |z| Point3 { x, y, z })))
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+// A problem with the puzzle input, located by its (1-based) line and optionally column
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: Option<usize>,
+    pub message: String
+}
+
+impl ParseError {
+    pub fn new(line: usize, message: String) -> Self {
+        ParseError { line, column: None, message }
+    }
+
+    pub fn with_column(self, column: usize) -> Self {
+        ParseError { column: Some(column), ..self }
+    }
+
+    // for errors found within a block of the input, which starts on a later line
+    pub fn offset(self, lines: usize) -> Self {
+        ParseError { line: self.line + lines, ..self }
+    }
+}
+
+// formatted to follow a filename, eg. "input.txt:42: expected 6 words"
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "{}:{}: {}", self.line, column, self.message),
+            None => write!(f, "{}: {}", self.line, self.message)
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::bounds::Bounds;
+use crate::error::ParseError;
+use crate::point::Point;
+use std::ops::{Index, IndexMut};
+
+// A dense rectangular grid, indexed by Point with (0,0) at the top left
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let height = rows.len();
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(format!("Row {} has {} columns but expected {}", y + 1, row.len(), width));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    // one row per line and one cell per character
+    pub fn parse<F: FnMut(char) -> Result<T, String>>(text: &str, mut cell: F) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (y, line) in text.lines().enumerate() {
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                row.push(cell(c).map_err(|e| ParseError::new(y + 1, e).with_column(x + 1))?);
+            }
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(y + 1, format!("expected {} columns but found {}", first.len(), row.len())));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).expect("rows already checked"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds::new(Point::ORIGIN, Point::new(self.width as isize - 1, self.height as isize - 1))
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.y as usize * self.width + p.x as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y as usize * self.width + p.x as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    // row by row, from the top left
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        self.bounds().points()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn position<F: Fn(&T) -> bool>(&self, predicate: F) -> Option<Point> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(p, _)| p)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // the inverse of parse, one line per row
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> String {
+        (0..self.height).map(|y| self.row(y).iter().map(&cell).collect::<String>()).collect::<Vec<String>>().join("\n")
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(&p).unwrap_or_else(|| panic!("Point ({},{}) is outside the {}x{} grid", p.x, p.y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(&p).unwrap_or_else(|| panic!("Point ({},{}) is outside the {}x{} grid", p.x, p.y, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit(c: char) -> Result<u32, String> {
+        c.to_digit(10).ok_or(format!("not a digit: {}", c))
+    }
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = Grid::parse("123\n456", digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.render(|n| char::from_digit(*n, 10).unwrap()), "123\n456");
+    }
+
+    #[test]
+    fn reports_bad_cells() {
+        assert_eq!(Grid::parse("123\n4x6", digit).unwrap_err(), ParseError::new(2, "not a digit: x".to_string()).with_column(2));
+        assert_eq!(Grid::parse("123\n45", digit).unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::error::ParseError;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::str::FromStr;
+
+const BYTE_ORDER_MARK: &[u8] = "\u{feff}".as_bytes();
+
+// Opens a puzzle input on disk (or stdin for "-") to be read as it goes, without normalising it
+pub fn open_input(filename: &str) -> io::Result<Box<dyn BufRead>> {
+    if filename == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(filename)?)))
+    }
+}
+
+// Reads a whole puzzle input, normalised so that every day can rely on "\n" line endings
+pub fn read_input(filename: &str) -> io::Result<String> {
+    let mut text = String::new();
+    open_input(filename)?.read_to_string(&mut text)?;
+    Ok(normalise(&text))
+}
+
+// for readers which aren't normalised, so that the first line doesn't start with one
+pub fn skip_byte_order_mark<R: BufRead>(reader: &mut R) -> io::Result<()> {
+    if reader.fill_buf()?.starts_with(BYTE_ORDER_MARK) {
+        reader.consume(BYTE_ORDER_MARK.len());
+    }
+    Ok(())
+}
+
+// Strips any byte order mark and trailing newlines, and converts CRLF (or lone CR) line endings to LF
+pub fn normalise(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    text.trim_end_matches('\n').to_string()
+}
+
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+}
+
+// Sections of normalised text separated by blank lines
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n")
+}
+
+// Blocks paired with the number of lines before them, to offset any ParseError found within
+pub fn numbered_blocks(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut skipped = 0;
+    blocks(text).map(move |block| {
+        let before = skipped;
+        skipped += block.lines().count() + 1;
+        (before, block)
+    })
+}
+
+// Parses every line with its FromStr, reporting the line number of the first failure
+pub fn parse_lines<T: FromStr<Err = String>>(text: &str) -> Result<Vec<T>, ParseError> {
+    parse_lines_with(text, |line| line.parse())
+}
+
+pub fn parse_lines_with<T, F: FnMut(&str) -> Result<T, String>>(text: &str, mut parse: F) -> Result<Vec<T>, ParseError> {
+    lines(text).enumerate().map(|(i, line)| parse(line).map_err(|e| ParseError::new(i + 1, e))).collect()
+}
+
+// A single whitespace separated number, with the offending text in the error
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, String> {
+    text.trim().parse().map_err(|_| format!("expected a number but found \"{}\"", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_line_endings() {
+        assert_eq!(normalise("\u{feff}a\r\nb\rc\n\n"), "a\nb\nc");
+        assert_eq!(normalise("a\n\nb"), "a\n\nb");
+    }
+
+    #[test]
+    fn skips_byte_order_mark() {
+        let mut reader = "\u{feff}a\nb".as_bytes();
+        skip_byte_order_mark(&mut reader).unwrap();
+        assert_eq!(reader, "a\nb".as_bytes());
+    }
+
+    #[test]
+    fn numbers_blocks_by_line() {
+        let blocks: Vec<(usize, &str)> = numbered_blocks("a\nb\n\nc\n\nd\ne").collect();
+        assert_eq!(blocks, vec![(0, "a\nb"), (3, "c"), (5, "d\ne")]);
+    }
+
+    #[test]
+    fn reports_failing_line() {
+        let error = parse_lines_with("1\n2\nthree", parse_number::<u32>).unwrap_err();
+        assert_eq!(error.to_string(), "3: expected a number but found \"three\"");
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+
+// A closed range of integers, eg. the sections 2-4 are 2, 3 and 4
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: isize,
+    pub end: isize
+}
+
+impl Interval {
+    pub fn new(start: isize, end: isize) -> Self {
+        assert!(start <= end, "interval {}-{} ends before it starts", start, end);
+        Interval { start, end }
+    }
+
+    pub fn point(at: isize) -> Self {
+        Interval { start: at, end: at }
+    }
+
+    // never empty, since start <= end
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        (self.end - self.start + 1) as usize
+    }
+
+    pub fn contains(&self, other: &Interval) -> bool {
+        other.start >= self.start && other.end <= self.end
+    }
+
+    pub fn contains_point(&self, at: isize) -> bool {
+        at >= self.start && at <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        if self.overlaps(other) {
+            Some(Interval { start: self.start.max(other.start), end: self.end.min(other.end) })
+        } else {
+            None
+        }
+    }
+
+    // only a single interval if they overlap or are next to each other
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        if self.start <= other.end + 1 && other.start <= self.end + 1 {
+            Some(Interval { start: self.start.min(other.start), end: self.end.max(other.end) })
+        } else {
+            None
+        }
+    }
+
+    // the parts of this interval not in other, which is at most one either side
+    pub fn subtract(&self, other: &Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        let mut parts = Vec::new();
+        if self.start < other.start {
+            parts.push(Interval { start: self.start, end: other.start - 1 });
+        }
+        if self.end > other.end {
+            parts.push(Interval { start: other.end + 1, end: self.end });
+        }
+        parts
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// A union of intervals, kept sorted and merged so that none of them overlap or touch
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // the intervals which merge with the new one are all together, from the first ending no more than 1 before it
+        let first = self.intervals.partition_point(|i| i.end + 1 < interval.start);
+        let mut merged = interval;
+        let mut last = first;
+        while last < self.intervals.len() {
+            match merged.union(&self.intervals[last]) {
+                Some(union) => merged = union,
+                None => break
+            }
+            last += 1;
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: &Interval) {
+        self.intervals = self.intervals.iter().flat_map(|i| i.subtract(interval)).collect();
+    }
+
+    pub fn contains_point(&self, at: isize) -> bool {
+        let i = self.intervals.partition_point(|i| i.end < at);
+        self.intervals.get(i).is_some_and(|i| i.contains_point(at))
+    }
+
+    // the total number of integers covered
+    pub fn len(&self) -> usize {
+        self.intervals.iter().map(|i| i.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    // the smallest interval covering the whole set
+    pub fn bounds(&self) -> Option<Interval> {
+        Some(Interval { start: self.intervals.first()?.start, end: self.intervals.last()?.end })
+    }
+
+    // the parts of within which are not covered
+    pub fn gaps(&self, within: &Interval) -> Vec<Interval> {
+        let mut gaps = IntervalSet::new();
+        gaps.insert(*within);
+        for interval in &self.intervals {
+            gaps.remove(interval);
+        }
+        gaps.intervals
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_intervals() {
+        let a = Interval::new(2, 6);
+        let b = Interval::new(4, 8);
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 6)));
+        assert_eq!(a.union(&b), Some(Interval::new(2, 8)));
+        assert_eq!(a.union(&Interval::new(7, 9)), Some(Interval::new(2, 9)));
+        assert_eq!(a.union(&Interval::new(8, 9)), None);
+        assert_eq!(a.subtract(&Interval::new(3, 4)), vec![Interval::new(2, 2), Interval::new(5, 6)]);
+        assert_eq!(a.subtract(&b), vec![Interval::new(2, 3)]);
+        assert!(a.contains(&Interval::new(3, 6)) && !a.contains(&b));
+    }
+
+    #[test]
+    fn merges_sets() {
+        let mut set: IntervalSet = [Interval::new(10, 12), Interval::new(1, 3), Interval::new(5, 6)].into_iter().collect();
+        assert_eq!(set.len(), 8);
+        set.insert(Interval::new(4, 9));
+        assert_eq!(set.intervals(), &[Interval::new(1, 12)]);
+        set.remove(&Interval::new(5, 6));
+        assert_eq!(set.intervals(), &[Interval::new(1, 4), Interval::new(7, 12)]);
+        assert!(set.contains_point(4) && !set.contains_point(5));
+        assert_eq!(set.gaps(&Interval::new(0, 15)), vec![Interval::new(0, 0), Interval::new(5, 6), Interval::new(13, 15)]);
+    }
+}
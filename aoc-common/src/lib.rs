@@ -0,0 +1,16 @@
+mod bounds;
+mod direction;
+mod error;
+mod grid;
+mod interval;
+pub mod input;
+mod point;
+mod solver;
+
+pub use bounds::{Bounds, Bounds3};
+pub use direction::Direction;
+pub use error::ParseError;
+pub use grid::Grid;
+pub use interval::{Interval, IntervalSet};
+pub use point::{Point, Point3};
+pub use solver::Solver;
@@ -0,0 +1,157 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// x grows to the right and y grows downwards, matching rows and columns of text input
+#[derive(Debug, Default, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Point) -> isize {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn signum(&self) -> Point {
+        Point { x: self.x.signum(), y: self.y.signum() }
+    }
+
+    // up, right, down, left
+    pub fn neighbours4(&self) -> impl Iterator<Item = Point> {
+        let p = *self;
+        [(0, -1), (1, 0), (0, 1), (-1, 0)].into_iter().map(move |(x, y)| p + Point { x, y })
+    }
+
+    // clockwise from the top left
+    pub fn neighbours8(&self) -> impl Iterator<Item = Point> {
+        let p = *self;
+        [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)].into_iter().map(move |(x, y)| p + Point { x, y })
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub fn new(x: isize, y: isize, z: isize) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Point3) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Point3) -> isize {
+        (self.x - other.x).abs().max((self.y - other.y).abs()).max((self.z - other.z).abs())
+    }
+
+    // the points sharing a face with this one
+    pub fn neighbours6(&self) -> impl Iterator<Item = Point3> {
+        let p = *self;
+        [(-1, 0, 0), (1, 0, 0), (0, -1, 0), (0, 1, 0), (0, 0, -1), (0, 0, 1)].into_iter().map(move |(x, y, z)| p + Point3 { x, y, z })
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3 { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3 { x: self.x - other.x, y: self.y - other.y, z: self.z - other.z }
+    }
+}
+
+impl Mul<isize> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, factor: isize) -> Point3 {
+        Point3 { x: self.x * factor, y: self.y * factor, z: self.z * factor }
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3 { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Point3) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, other: Point3) {
+        *self = *self - other;
+    }
+}
@@ -0,0 +1,36 @@
+use crate::error::ParseError;
+use crate::input::normalise;
+use std::io::{self, BufRead};
+
+// Each day implements this so the runner can load, parse and solve it without knowing the details.
+// Any day-specific settings (rock counts, minutes, search ranges) live on the implementing struct,
+// with a Default matching the puzzle.
+pub trait Solver {
+    type Input;
+
+    // text has already been normalised, see input::normalise
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError>;
+
+    // Used by the runner to parse the input as it is read, which by default means reading all of it and normalising it
+    // for parse. Days which only need to keep a little of their input override this, and then have to cope with a byte
+    // order mark and CRLF line endings themselves.
+    fn parse_reader<R: BufRead>(&self, mut reader: R) -> io::Result<Result<Self::Input, ParseError>> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Ok(self.parse(&normalise(&text)))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String;
+
+    fn part2(&self, input: &Self::Input) -> String;
+
+    // anything odd about the input which was worked around rather than stopping it being solved
+    fn warnings(&self, _input: &Self::Input) -> Vec<ParseError> {
+        Vec::new()
+    }
+
+    // anything else worth showing about the input, printed by run --details
+    fn details(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+}
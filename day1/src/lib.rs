@@ -0,0 +1,220 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{parse_number, skip_byte_order_mark};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+
+const HISTOGRAM_BUCKETS: u64 = 10;
+// the most elves whose calories are kept for the median and histogram, so that memory stays bounded however long the input
+const SUMS_KEPT: usize = 100000;
+
+pub struct Day1 {
+    pub top: usize
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Day1 {
+            top: 3
+        }
+    }
+}
+
+impl Solver for Day1 {
+    type Input = Inventory;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        Inventory::read(text.as_bytes(), self.top)
+    }
+
+    fn parse_reader<R: BufRead>(&self, reader: R) -> io::Result<Result<Self::Input, ParseError>> {
+        Ok(Inventory::read(reader, self.top))
+    }
+
+    fn part1(&self, inventory: &Self::Input) -> String {
+        format!("{}", inventory.top.first().map(|e| e.calories).expect("will have at least 1"))
+    }
+
+    fn part2(&self, inventory: &Self::Input) -> String {
+        format!("{}", inventory.top.iter().map(|e| e.calories).sum::<u64>())
+    }
+
+    fn details(&self, inventory: &Self::Input) -> Option<String> {
+        Some(inventory.summary())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Elf {
+    pub calories: u64,
+    pub index: usize
+}
+
+// The elves carrying the most calories, along with enough about the rest for summary statistics
+pub struct Inventory {
+    pub top: Vec<Elf>,
+    pub elves: usize,
+    pub total: u64,
+    // just one number per elf (rather than per item) to find the median and histogram, or None after SUMS_KEPT elves
+    sums: Option<Vec<u64>>
+}
+
+impl Inventory {
+    // streams the lines of any reader, keeping only the top k elves (numbered from 1), and ignoring any blank lines at the end
+    pub fn read<R: BufRead>(mut reader: R, k: usize) -> Result<Self, ParseError> {
+        skip_byte_order_mark(&mut reader).map_err(|e| ParseError::new(1, e.to_string()))?;
+        let mut heap: BinaryHeap<Reverse<Elf>> = BinaryHeap::new();
+        let mut sums = Some(Vec::new());
+        let mut elves = 0;
+        let mut total: u64 = 0;
+        let mut sum: u64 = 0;
+        // each blank line finishes an elf, but only once another line shows it wasn't the end of the input
+        let mut blanks = 0;
+        let mut i = 0;
+        for line in reader.lines() {
+            i += 1;
+            let line = line.map_err(|e| ParseError::new(i, e.to_string()))?;
+            if line.is_empty() {
+                blanks += 1;
+                continue;
+            }
+            for _ in 0..blanks {
+                elves += 1;
+                finish_elf(&mut heap, &mut sums, Elf { calories: sum, index: elves }, k);
+                sum = 0;
+            }
+            blanks = 0;
+            let value: u64 = parse_number(&line).map_err(|e| ParseError::new(i, e))?;
+            sum = sum.checked_add(value).ok_or(ParseError::new(i, "calories overflowed".to_string()))?;
+            total = total.checked_add(value).ok_or(ParseError::new(i, "total calories overflowed".to_string()))?;
+        }
+        elves += 1;
+        finish_elf(&mut heap, &mut sums, Elf { calories: sum, index: elves }, k);
+        let mut top: Vec<Elf> = heap.into_iter().map(|Reverse(e)| e).collect();
+        top.sort_by(|a, b| b.cmp(a));
+        Ok(Inventory {
+            top,
+            elves,
+            total,
+            sums
+        })
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.total as f64 / self.elves as f64
+    }
+
+    // None if there were too many elves to keep them all
+    pub fn median(&self) -> Option<f64> {
+        let mut sorted = self.sums.clone()?;
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            Some((sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0)
+        } else {
+            Some(sorted[middle] as f64)
+        }
+    }
+
+    // the number of elves in each of equally sized ranges of calories, from the least to the most carried,
+    // or None if there were too many elves to keep them all
+    pub fn histogram(&self) -> Option<Vec<(u64, u64, usize)>> {
+        let sums = self.sums.as_ref()?;
+        let min = *sums.iter().min().expect("will have at least 1");
+        let max = *sums.iter().max().expect("will have at least 1");
+        let width = ((max - min) / HISTOGRAM_BUCKETS) + 1;
+        let mut counts = vec![0; ((max - min) / width + 1) as usize];
+        for sum in sums {
+            counts[((sum - min) / width) as usize] += 1;
+        }
+        Some(counts.into_iter().enumerate().map(|(i, c)| (min + i as u64 * width, min + (i as u64 + 1) * width - 1, c)).collect())
+    }
+
+    pub fn summary(&self) -> String {
+        let mut lines = Vec::new();
+        let top: Vec<String> = self.top.iter().map(|e| format!("elf {} ({})", e.index, e.calories)).collect();
+        lines.push(format!("Top {}: {}", self.top.len(), top.join(", ")));
+        let (Some(median), Some(histogram)) = (self.median(), self.histogram()) else {
+            lines.push(format!("Elves: {}, mean {:.1} (too many for the median and histogram, which need at most {})", self.elves, self.mean(), SUMS_KEPT));
+            return lines.join("\n");
+        };
+        lines.push(format!("Elves: {}, mean {:.1}, median {:.1}", self.elves, self.mean(), median));
+        let most = histogram.iter().map(|(_, _, c)| *c).max().unwrap_or(0);
+        for (from, to, count) in histogram {
+            // scaled so that the most common range is 50 wide
+            let bar = "#".repeat((count * 50).div_ceil(most.max(1)));
+            lines.push(format!("{:>10}-{:<10} {:>6} {}", from, to, count, bar));
+        }
+        lines.join("\n")
+    }
+}
+
+fn finish_elf(heap: &mut BinaryHeap<Reverse<Elf>>, sums: &mut Option<Vec<u64>>, elf: Elf, k: usize) {
+    if let Some(kept) = sums {
+        if kept.len() < SUMS_KEPT {
+            kept.push(elf.calories);
+        } else {
+            *sums = None;
+        }
+    }
+    heap.push(Reverse(elf));
+    if heap.len() > k.max(1) {
+        heap.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day1::default().part1(&input), "24000");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day1::default().part2(&input), "45000");
+    }
+
+    #[test]
+    fn sums_each_elf() {
+        let inventory = Inventory::read("1\n2\n\n3\n\n\n4".as_bytes(), 2).unwrap();
+        assert_eq!(inventory.sums, Some(vec![3, 3, 0, 4]));
+        assert_eq!(inventory.top, vec![Elf { calories: 4, index: 4 }, Elf { calories: 3, index: 2 }]);
+        assert_eq!(Inventory::read("1\nx".as_bytes(), 3).err().unwrap().line, 2);
+        // unnormalised, as read by the runner
+        let inventory = Inventory::read("\u{feff}1\r\n\r\n2\r\n\r\n\r\n".as_bytes(), 3).unwrap();
+        assert_eq!(inventory.sums, Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn summarises_elves() {
+        let inventory = Inventory::read(normalise(EXAMPLE).as_bytes(), 3).unwrap();
+        assert_eq!(inventory.mean(), 11000.0);
+        assert_eq!(inventory.median(), Some(10000.0));
+        let counts: Vec<usize> = inventory.histogram().unwrap().iter().map(|(_, _, c)| *c).collect();
+        assert_eq!(counts.iter().sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn bounds_memory() {
+        let text = "1\n\n".repeat(SUMS_KEPT) + "2";
+        let inventory = Inventory::read(text.as_bytes(), 3).unwrap();
+        assert_eq!(inventory.elves, SUMS_KEPT + 1);
+        assert_eq!(inventory.sums, None);
+        assert_eq!(inventory.median(), None);
+        assert_eq!(inventory.top[0], Elf { calories: 2, index: SUMS_KEPT + 1 });
+    }
+
+    #[test]
+    fn does_not_overflow() {
+        let inventory = Inventory::read("4000000000\n4000000000".as_bytes(), 3).unwrap();
+        assert_eq!(inventory.total, 8000000000);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{parse_lines, parse_number};
+use std::str::FromStr;
+
+enum Instruction {
+    Noop,
+    AddX(isize)
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct State {
+    x: isize
+}
+
+const LAST_IMPORTANT_CYCLE: usize = 220;
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<State>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let instructions: Vec<Instruction> = parse_lines(text)?;
+        let mut cycles: Vec<State> = vec![State { x: 1 }];
+        for instruction in &instructions {
+            let mut new_states = run_instruction(cycles.last().unwrap(), instruction);
+            cycles.append(&mut new_states);
+        }
+        // part 1 needs the signal during the last of its important cycles
+        let ran = cycles.len() - 1;
+        if ran < LAST_IMPORTANT_CYCLE {
+            return Err(ParseError::new(instructions.len().max(1), format!("program only runs {} cycles but {} are needed", ran, LAST_IMPORTANT_CYCLE)));
+        }
+        Ok(cycles)
+    }
+
+    fn part1(&self, cycles: &Self::Input) -> String {
+        let important_cycles = vec![20, 60, 100, 140, 180, LAST_IMPORTANT_CYCLE];
+        let mut sum = 0;
+        for i in important_cycles {
+            sum += cycles[i-1].x * i as isize;
+        }
+        format!("{}", sum)
+    }
+
+    fn part2(&self, cycles: &Self::Input) -> String {
+        let mut lines = Vec::new();
+        let mut i = 0;
+        while i + 40 < cycles.len() {
+            lines.push(render_line(&cycles[i..i+40]));
+            i += 40;
+        }
+        lines.join("\n")
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = line.split(" ").collect();
+        match parts[..] {
+            ["noop"] => Ok(Instruction::Noop),
+            ["addx", value] => Ok(Instruction::AddX(parse_number(value)?)),
+            _ => Err(format!("unknown instruction \"{}\"", line))
+        }
+    }
+}
+
+fn run_instruction(previous: &State, instruction: &Instruction) -> Vec<State> {
+    match instruction {
+        Instruction::Noop => vec![*previous],
+        Instruction::AddX(x) => vec![*previous, State { x: previous.x + x }]
+    }
+}
+
+fn render_line(cycles: &[State]) -> String {
+    cycles.iter().enumerate().map(|(i,s)| if (i as isize - s.x).abs() <= 1 {
+        '#'
+    } else {
+        '.'
+    }).collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day10.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day10.part1(&input), "13140");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10.parse(&normalise(EXAMPLE)).unwrap();
+        let expected = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######....."
+        ].join("\n");
+        assert_eq!(Day10.part2(&input), expected);
+    }
+
+    #[test]
+    fn rejects_short_programs() {
+        let error = Day10.parse("noop\naddx 3").unwrap_err();
+        assert_eq!(error.to_string(), "2: program only runs 3 cycles but 220 are needed");
+    }
+}
@@ -0,0 +1,183 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{lines, numbered_blocks, parse_number};
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct Monkey {
+    throw_count: u64,
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test
+}
+
+#[derive(Clone)]
+enum Operation {
+    Multiply(u64),
+    Add(u64),
+    Square
+}
+
+#[derive(Clone)]
+struct Test {
+    divisible_by: u64,
+    true_index: usize,
+    false_index: usize
+}
+
+#[derive(Default)]
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let mut monkies = Vec::new();
+        for (offset, block) in numbered_blocks(text) {
+            let monkey = parse_monkey(block).map_err(|e| e.offset(offset))?;
+            for (line, index) in [(5, monkey.test.true_index), (6, monkey.test.false_index)] {
+                if index == monkies.len() {
+                    return Err(ParseError::new(offset + line, "monkey cannot throw to itself".to_string()));
+                }
+            }
+            monkies.push(monkey);
+        }
+        for (i, monkey) in monkies.iter().enumerate() {
+            if let Some(index) = [monkey.test.true_index, monkey.test.false_index].into_iter().find(|m| *m >= monkies.len()) {
+                return Err(ParseError::new(1, format!("monkey {} throws to monkey {} which does not exist", i, index)));
+            }
+        }
+        Ok(monkies)
+    }
+
+    fn part1(&self, monkies: &Self::Input) -> String {
+        let mut monkies = monkies.clone();
+        let allowable_fudge_modulo: u64 = monkies.iter().map(|m| m.test.divisible_by).product();
+        for _ in 0..20 {
+            run_round(&mut monkies, 3, allowable_fudge_modulo);
+        }
+        format!("{}", monkey_business(&monkies))
+    }
+
+    fn part2(&self, monkies: &Self::Input) -> String {
+        let mut monkies = monkies.clone();
+        let allowable_fudge_modulo: u64 = monkies.iter().map(|m| m.test.divisible_by).product();
+        for _ in 0..10000 {
+            run_round(&mut monkies, 1, allowable_fudge_modulo);
+        }
+        format!("{}", monkey_business(&monkies))
+    }
+}
+
+fn monkey_business(monkies: &[Monkey]) -> u64 {
+    let mut throws: Vec<u64> = monkies.iter().map(|m| m.throw_count).collect();
+    throws.sort();
+    throws.reverse();
+    throws.iter().take(2).product::<u64>()
+}
+
+fn parse_monkey(text: &str) -> Result<Monkey, ParseError> {
+    let lines: Vec<&str> = lines(text).collect();
+    if lines.len() != 6 {
+        return Err(ParseError::new(1, format!("expected 6 lines describing a monkey but found {}", lines.len())));
+    }
+    //Monkey 0:
+    //  Starting items: 79, 98
+    //  Operation: new = old * 19
+    //  Test: divisible by 23
+    //    If true: throw to monkey 2
+    //    If false: throw to monkey 3
+    let items = field(&lines, 1, "Starting items: ")?;
+    Ok(Monkey {
+        throw_count: 0,
+        items: items.split(", ").map(parse_number).collect::<Result<_, _>>().map_err(|e| ParseError::new(2, e))?,
+        operation: field(&lines, 2, "Operation: ")?.parse().map_err(|e| ParseError::new(3, e))?,
+        test: Test {
+            divisible_by: parse_number(field(&lines, 3, "Test: divisible by ")?).map_err(|e| ParseError::new(4, e))?,
+            true_index: parse_number(field(&lines, 4, "If true: throw to monkey ")?).map_err(|e| ParseError::new(5, e))?,
+            false_index: parse_number(field(&lines, 5, "If false: throw to monkey ")?).map_err(|e| ParseError::new(6, e))?
+        }
+    })
+}
+
+// the rest of the indexed line after its expected label
+fn field<'a>(lines: &[&'a str], index: usize, label: &str) -> Result<&'a str, ParseError> {
+    lines[index].trim_start().strip_prefix(label)
+        .ok_or_else(|| ParseError::new(index + 1, format!("expected \"{}\" in \"{}\"", label.trim(), lines[index])))
+}
+
+impl FromStr for Operation {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        //new = old * 19
+        let words: Vec<&str> = line.split(" ").collect();
+        if words.len() != 5 || words[0] != "new" || words[1] != "=" || words[2] != "old" {
+            return Err(format!("expected \"new = old <op> <value>\" but found \"{}\"", line));
+        }
+        Ok(if words[4].eq("old") {
+            match words[3] {
+                "*" => Operation::Square,
+                "+" => Operation::Multiply(2),
+                _ => return Err(format!("unknown operator \"{}\"", words[3]))
+            }
+        } else {
+            let literal: u64 = parse_number(words[4])?;
+            match words[3] {
+                "*" => Operation::Multiply(literal),
+                "+" => Operation::Add(literal),
+                _ => return Err(format!("unknown operator \"{}\"", words[3]))
+            }
+        })
+    }
+}
+
+fn run_round(monkies: &mut [Monkey], worry_decrease_factor: u64, allowable_fudge_modulo: u64) {
+    for i in 0..monkies.len() {
+        let mut self_throw: VecDeque<u64> = VecDeque::new();
+        while let Some(item) = monkies[i].items.pop_front() {
+            let new_item = run_operation(item, &monkies[i].operation, worry_decrease_factor, allowable_fudge_modulo);
+            let new_monkey = run_test(new_item, &monkies[i].test);
+            monkies[new_monkey].items.push_back(new_item);
+            monkies[i].throw_count += 1;
+        }
+        monkies[i].items.append(&mut self_throw);
+    }
+}
+
+fn run_operation(old: u64, operation: &Operation, worry_decrease_factor: u64, allowable_fudge_modulo: u64) -> u64 {
+    let new = match operation {
+        Operation::Square => (old % allowable_fudge_modulo).pow(2),
+        Operation::Add(delta) => old + delta,
+        Operation::Multiply(factor) => (old % allowable_fudge_modulo) * factor
+    };
+    new / worry_decrease_factor
+}
+
+fn run_test(value: u64, test: &Test) -> usize {
+    if value.is_multiple_of(test.divisible_by) {
+        test.true_index
+    } else {
+        test.false_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day11.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day11.part1(&input), "10605");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day11.part2(&input), "2713310158");
+    }
+}
\ No newline at end of file
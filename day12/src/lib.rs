@@ -0,0 +1,87 @@
+use aoc_common::{Grid, ParseError, Point, Solver};
+use pathfinding::prelude::bfs;
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = (Grid<u32>, Point, Point);
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let markers: Grid<char> = Grid::parse(text, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c),
+            _ => Err(format!("expected a height a-z, S or E but found '{}'", c))
+        })?;
+        let start: Point = markers.position(|c| *c == 'S').ok_or(ParseError::new(1, "start marker S not found".to_string()))?;
+        let finish: Point = markers.position(|c| *c == 'E').ok_or(ParseError::new(1, "finish marker E not found".to_string()))?;
+        let grid: Grid<u32> = markers.map(|c| match c {
+            'S' => 'a' as u32,
+            'E' => 'z' as u32,
+            c => *c as u32
+        });
+        Ok((grid, start, finish))
+    }
+
+    fn part1(&self, (grid, start, finish): &Self::Input) -> String {
+        let path_from_start = bfs(
+            finish,
+            |p| successors(grid, p),
+            |p| *p == *start
+        );
+        describe(path_from_start)
+    }
+
+    fn part2(&self, (grid, _, finish): &Self::Input) -> String {
+        let path_from_a = bfs(
+            finish,
+            |p| successors(grid, p),
+            |p| grid[*p] == 'a' as u32
+        );
+        describe(path_from_a)
+    }
+}
+
+// the steps along a path found by bfs, or none if the finish can't be reached
+fn describe(path: Option<Vec<Point>>) -> String {
+    match path {
+        Some(path) => format!("{}", path.len() - 1),
+        None => "none".to_string()
+    }
+}
+
+fn successors(grid: &Grid<u32>, from: &Point) -> Vec<Point> {
+    from.neighbours4().filter(|to| grid.contains(to) && is_valid(grid, from, to)).collect()
+}
+
+fn is_valid(grid: &Grid<u32>, from: &Point, to: &Point) -> bool {
+    let from_value = grid[*from];
+    let to_value = grid[*to];
+    from_value <= to_value + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day12.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day12.part1(&input), "31");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day12.part2(&input), "29");
+    }
+
+    #[test]
+    fn finds_no_path() {
+        let input = Day12.parse("SabE").unwrap();
+        assert_eq!(Day12.part1(&input), "none");
+        assert_eq!(Day12.part2(&input), "none");
+    }
+}
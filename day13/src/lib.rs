@@ -0,0 +1,168 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{numbered_blocks, parse_lines, parse_number};
+use std::str::FromStr;
+use core::cmp::Ordering;
+
+#[derive(Clone)]
+pub enum Item {
+    Int(isize),
+    List(Vec<Item>)
+}
+
+#[derive(Default)]
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Vec<Item>>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let mut pairs = Vec::new();
+        for (offset, block) in numbered_blocks(text) {
+            let pair: Vec<Item> = parse_lines(block).map_err(|e| e.offset(offset))?;
+            if pair.len() != 2 {
+                return Err(ParseError::new(offset + 1, format!("expected a pair of packets but found {}", pair.len())));
+            }
+            pairs.push(pair);
+        }
+        Ok(pairs)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> String {
+        let mut sum = 0;
+        for (i, pair) in pairs.iter().enumerate() {
+            if pair[0].cmp(&pair[1]) == Ordering::Less {
+                sum += i + 1;
+            }
+        }
+        format!("{}", sum)
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> String {
+        let mut packets: Vec<Item> = vec!["[[2]]".parse().unwrap(), "[[6]]".parse().unwrap()];
+        for pair in pairs {
+            packets.extend(pair.iter().cloned());
+        }
+        packets.sort();
+        let i1 = packets.iter().position(|p| *p == "[[2]]".parse().unwrap()).unwrap() + 1;
+        let i2 = packets.iter().position(|p| *p == "[[6]]".parse().unwrap()).unwrap() + 1;
+        format!("{}", i1*i2)
+    }
+}
+
+impl FromStr for Item {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        // [[1],[2,3,4]]
+        if line.is_empty() {
+            Ok(Item::List(Vec::new()))
+        } else if !line.starts_with('[') || !line.ends_with(']') {
+            Ok(Item::Int(parse_number(line)?))
+        } else {
+            Ok(Item::List(split_by_comma(&line[1..line.len()-1])?.iter().map(|s| s.parse()).collect::<Result<_, _>>()?))
+        }
+    }
+}
+
+fn split_by_comma(line: &str) -> Result<Vec<String>, String> {
+    let mut depth = 0;
+    let mut strings: Vec<String> = Vec::new();
+    let mut current: Vec<char> = Vec::new();
+    for c in line.chars() {
+        match c {
+            '[' => {
+                depth += 1;
+                current.push(c);
+            }
+            ']' if depth == 0 => return Err(format!("unmatched ']' in \"{}\"", line)),
+            ']' => {
+                depth -= 1;
+                current.push(c);
+            },
+            ',' if depth == 0 => {
+                strings.push(current.iter().collect());
+                current = Vec::new();
+            },
+            _ => current.push(c),
+        }
+    }
+    if depth != 0 {
+        return Err(format!("unmatched '[' in \"{}\"", line));
+    }
+    strings.push(current.iter().collect());
+    Ok(strings)
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self,other) {
+            (Item::Int(a_int), Item::Int(b_int)) => a_int.cmp(b_int),
+            (Item::List(a_list), Item::List(b_list)) => {
+                let mut i = 0;
+                while i < a_list.len() && i < b_list.len() {
+                    let result = a_list[i].cmp(&b_list[i]);
+                    if result != Ordering::Equal {
+                        return result;
+                    }
+                    i += 1;
+                }
+                a_list.len().cmp(&b_list.len())
+            },
+            (Item::Int(a_int), Item::List(_)) => Item::List(vec![Item::Int(*a_int)]).cmp(other),
+            (Item::List(_), Item::Int(b_int)) => self.cmp(&Item::List(vec![Item::Int(*b_int)]))
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Item {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day13.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day13.part1(&input), "13");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day13.part2(&input), "140");
+    }
+
+    fn compare(a: &str, b: &str) -> Ordering {
+        a.parse::<Item>().unwrap().cmp(&b.parse().unwrap())
+    }
+
+    #[test]
+    fn compares_packets() {
+        assert_eq!(compare("[1,1,3,1,1]", "[1,1,5,1,1]"), Ordering::Less);
+        assert_eq!(compare("[[1],[2,3,4]]", "[[1],4]"), Ordering::Less);
+        assert_eq!(compare("[9]", "[[8,7,6]]"), Ordering::Greater);
+        assert_eq!(compare("[[]]", "[[]]"), Ordering::Equal);
+        assert_eq!(compare("[]", "[3]"), Ordering::Less);
+    }
+
+    #[test]
+    fn rejects_unbalanced_packets() {
+        assert!("[[1]".parse::<Item>().is_err());
+        assert!("[1]]".parse::<Item>().is_err());
+    }
+}
\ No newline at end of file
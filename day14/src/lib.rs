@@ -0,0 +1,153 @@
+use aoc_common::{Bounds, ParseError, Point, Solver};
+use aoc_common::input::{parse_lines, parse_number};
+use std::str::FromStr;
+use std::collections::HashMap;
+
+enum Fill {
+    Rock,
+    Sand
+}
+
+pub struct Path {
+    points: Vec<Point>
+}
+
+#[derive(Default)]
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Vec<Path>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let paths: Vec<Path> = parse_lines(text)?;
+        if paths.is_empty() {
+            return Err(ParseError::new(1, "expected at least one path of rock".to_string()));
+        }
+        Ok(paths)
+    }
+
+    fn part1(&self, paths: &Self::Input) -> String {
+        let mut grid: HashMap<Point,Fill> = HashMap::new();
+        for path in paths {
+            path.draw_rock(&mut grid);
+        }
+        let lowest_rock = grid.keys().map(|p| p.y).max().unwrap();
+        let start = Point { x: 500, y: 0 };
+        let mut sand_count = 0;
+        while fall_sand(&mut grid, start, Some(lowest_rock), None).is_some() {
+            sand_count += 1;
+        }
+        format!("{}", sand_count)
+    }
+
+    fn part2(&self, paths: &Self::Input) -> String {
+        let mut grid: HashMap<Point,Fill> = HashMap::new();
+        for path in paths {
+            path.draw_rock(&mut grid);
+        }
+        let lowest_rock = grid.keys().map(|p| p.y).max().unwrap();
+        let start = Point { x: 500, y: 0 };
+        let mut sand_count = 0;
+        let floor = lowest_rock + 2;
+        while let Some(last) = fall_sand(&mut grid, start, None, Some(floor)) {
+            sand_count += 1;
+            if last == start {
+                break;
+            }
+        }
+        format!("{}", sand_count)
+    }
+}
+
+impl FromStr for Path {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let points: Vec<Point> = line.split(" -> ").map(parse_point).collect::<Result<_, _>>()?;
+        // a single point would draw no rock
+        if points.len() < 2 {
+            return Err(format!("expected at least 2 points in \"{}\"", line));
+        }
+        Ok(Path { points })
+    }
+}
+
+fn parse_point(segment: &str) -> Result<Point, String> {
+    let numbers: Vec<&str> = segment.split(",").collect();
+    if numbers.len() != 2 {
+        Err(format!("expected 2 numbers in \"{}\"", segment))
+    } else {
+        Ok(Point {
+            x: parse_number(numbers[0])?,
+            y: parse_number(numbers[1])?
+        })
+    }
+}
+
+impl Path {
+    fn draw_rock(&self, grid: &mut HashMap<Point, Fill>) {
+        for i in 1..self.points.len() {
+            let line = Bounds::from_points(&self.points[(i - 1)..(i + 1)]).unwrap();
+            for p in line.points() {
+                grid.insert(p, Fill::Rock);
+            }
+        }
+    }
+}
+
+fn fall_sand(grid: &mut HashMap<Point, Fill>, p: Point, lowest_rock: Option<isize>, floor_level: Option<isize>) -> Option<Point> {
+    if let Some(low) = lowest_rock {
+        if p.y >= low {
+            // sand falls into the abyss
+            return None;
+        }
+    }
+    if let Some(floor) = floor_level {
+        if p.y == floor - 1 {
+            // on the floor
+            grid.insert(p, Fill::Sand);
+            return Some(p);
+        }
+    }
+    let down_centre = Point { x: p.x, y: p.y + 1 };
+    if !grid.contains_key(&down_centre) {
+        return fall_sand(grid, down_centre, lowest_rock, floor_level);
+    }
+    let down_left = Point { x: p.x - 1, y: p.y + 1 };
+    if !grid.contains_key(&down_left) {
+        return fall_sand(grid, down_left, lowest_rock, floor_level);
+    }
+    let down_right = Point { x: p.x + 1, y: p.y + 1 };
+    if !grid.contains_key(&down_right) {
+        return fall_sand(grid, down_right, lowest_rock, floor_level);
+    }
+    grid.insert(p, Fill::Sand);
+    Some(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day14.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day14.part1(&input), "24");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day14.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day14.part2(&input), "93");
+    }
+
+    #[test]
+    fn rejects_missing_rock() {
+        let error = |text| Day14.parse(text).err().map(|e| e.to_string());
+        assert_eq!(error(""), Some("1: expected at least one path of rock".to_string()));
+        assert_eq!(error("498,4 -> 498,6\n500,0"), Some("2: expected at least 2 points in \"500,0\"".to_string()));
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_common::{Interval, IntervalSet, ParseError, Point, Solver};
+use aoc_common::input::{parse_lines, parse_number};
+use std::str::FromStr;
+
+pub struct Sensor {
+    position: Point,
+    beacon: Point,
+    search_distance: isize
+}
+
+pub struct Day15 {
+    pub row: isize,
+    pub search_min: isize,
+    pub search_max: isize,
+    pub search_size: isize
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            row: 2000000,
+            search_min: 0,
+            search_max: 4000000,
+            search_size: 4000000
+        }
+    }
+}
+
+impl Solver for Day15 {
+    type Input = Vec<Sensor>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let sensors: Vec<Sensor> = parse_lines(text)?;
+        if sensors.is_empty() {
+            return Err(ParseError::new(1, "expected at least one sensor".to_string()));
+        }
+        Ok(sensors)
+    }
+
+    fn part1(&self, sensors: &Self::Input) -> String {
+        let covered = coverage(sensors, self.row);
+        // beacons are inside their sensor's coverage, but might still be beacons
+        let mut beacons: Vec<isize> = sensors.iter().filter(|s| s.beacon.y == self.row).map(|s| s.beacon.x).collect();
+        beacons.sort();
+        beacons.dedup();
+        format!("{}", covered.len() - beacons.len())
+    }
+
+    fn part2(&self, sensors: &Self::Input) -> String {
+        if self.search_min > self.search_max {
+            return String::new(); // no columns to search
+        }
+        let columns = Interval::new(self.search_min, self.search_max);
+        let mut found: Vec<String> = Vec::new();
+        for y in 0..(self.search_size+1) {
+            // any gap in the coverage is out of range of every sensor, so can't be a known beacon either
+            for gap in coverage(sensors, y).gaps(&columns) {
+                for x in gap.start..(gap.end+1) {
+                    found.push(format!("{}", x*4000000+y));
+                }
+            }
+        }
+        found.join(", ")
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = line.split(": ").collect();
+        if segments.len() != 2 {
+            return Err(format!("expected a sensor and a beacon in \"{}\"", line));
+        }
+        let position: Point = parse_point(segments[0], "Sensor at ")?; //Sensor at x=2, y=18
+        let beacon: Point = parse_point(segments[1], "closest beacon is at ")?; //closest beacon is at x=-2, y=15
+        Ok(Sensor {
+            position,
+            beacon,
+            search_distance: position.manhattan(&beacon)
+        })
+    }
+}
+
+fn parse_point(segment: &str, label: &str) -> Result<Point, String> {
+    let coordinates = segment.strip_prefix(label).ok_or(format!("expected \"{}\" in \"{}\"", label.trim(), segment))?;
+    let numbers: Vec<&str> = coordinates.split(", ").collect();
+    match numbers[..] {
+        [x, y] => Ok(Point {
+            x: parse_number(x.strip_prefix("x=").ok_or(format!("expected x= in \"{}\"", segment))?)?,//x=2
+            y: parse_number(y.strip_prefix("y=").ok_or(format!("expected y= in \"{}\"", segment))?)?//y=16
+        }),
+        _ => Err(format!("expected 2 coordinates in \"{}\"", segment))
+    }
+}
+
+// the columns of a row which are no further from a sensor than its closest beacon
+fn coverage(sensors: &[Sensor], row: isize) -> IntervalSet {
+    sensors.iter().filter_map(|s| {
+        let reach = s.search_distance - (s.position.y - row).abs();
+        if reach < 0 {
+            None
+        } else {
+            Some(Interval::new(s.position.x - reach, s.position.x + reach))
+        }
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let solver = Day15 { row: 10, search_min: 0, search_max: 20, search_size: 20 };
+        let input = solver.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(solver.part1(&input), "26");
+    }
+
+    #[test]
+    fn part2_example() {
+        let solver = Day15 { row: 10, search_min: 0, search_max: 20, search_size: 20 };
+        let input = solver.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(solver.part2(&input), "56000011");
+    }
+
+    #[test]
+    fn rejects_missing_sensors() {
+        let error = Day15::default().parse("").err().map(|e| e.to_string());
+        assert_eq!(error, Some("1: expected at least one sensor".to_string()));
+    }
+}
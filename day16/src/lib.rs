@@ -1,57 +1,79 @@
-use std::env;
-use std::fs;
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{parse_lines, parse_number};
+use std::str::FromStr;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use pathfinding::prelude::bfs;
 use itertools::Itertools;
 
-struct Valve {
+pub struct Valve {
     name: String,
     rate: usize,
     tunnels: Vec<String>
 }
 
-const NL: &str = "\n";
+#[derive(Default)]
+pub struct Day16;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let mut valves: HashMap<String, Valve> = HashMap::new();
-        for line in text.split(NL) { //Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-            let sections: Vec<&str> = line.split("; ").collect();
-            let first: Vec<&str> = sections[0].split(" ").collect();
-            let name = first[1].to_string();
-            let rate = first[4].split("=").skip(1).next().unwrap().parse().unwrap();
-            let tunnels: Vec<String> = sections[1].split(" ").skip(4).map(|s| s[0..2].to_string()).collect();
-            let valve = Valve { name: name.clone(), rate, tunnels };
-            valves.insert(name, valve);
+impl Solver for Day16 {
+    type Input = HashMap<String, Valve>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let list: Vec<Valve> = parse_lines(text)?;
+        for (i, valve) in list.iter().enumerate() {
+            if let Some(tunnel) = valve.tunnels.iter().find(|t| !list.iter().any(|v| v.name == **t)) {
+                return Err(ParseError::new(i + 1, format!("tunnel leads to unknown valve {}", tunnel)));
+            }
+        }
+        if !list.iter().any(|v| v.name == "AA") {
+            return Err(ParseError::new(1, "starting valve AA not found".to_string()));
         }
-        println!("Valves: {}", valves.len());
+        Ok(list.into_iter().map(|v| (v.name.clone(), v)).collect())
+    }
+
+    fn part1(&self, valves: &Self::Input) -> String {
+        let useful: HashSet<String> = valves.values().filter(|v| v.rate > 0).map(|v| v.name.clone()).collect();
+        let mut cache: HashMap<(String, String),usize> = HashMap::new();
+        format!("{}", best_simulation(valves, "AA", &useful, 30, &mut cache))
+    }
+
+    fn part2(&self, valves: &Self::Input) -> String {
         let useful: HashSet<String> = valves.values().filter(|v| v.rate > 0).map(|v| v.name.clone()).collect();
-        println!("Useful: {}", useful.len());
         let mut cache: HashMap<(String, String),usize> = HashMap::new();
-        let best = best_simulation(&valves, "AA", &useful, 30, &mut cache);
-        println!("Part1 Best: {}", best);
         let mut best_total = 0;
         for half in useful.clone().into_iter().combinations(useful.len() / 2) {
             let me = half.into_iter().collect();
             let elephant: HashSet<String> = useful.difference(&me).map(|s| s.to_string()).collect();
-            let total = best_simulation(&valves, "AA", &me, 26, &mut cache) + best_simulation(&valves, "AA", &elephant, 26, &mut cache);
+            let total = best_simulation(valves, "AA", &me, 26, &mut cache) + best_simulation(valves, "AA", &elephant, 26, &mut cache);
             if total > best_total {
                 best_total = total;
             }
         }
-        println!("Part2 Best: {}", best_total);
-    } else {
-        println!("Please provide 1 argument: Filename");
+        format!("{}", best_total)
+    }
+}
+
+impl FromStr for Valve {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        //Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+        let sections: Vec<&str> = line.split("; ").collect();
+        let first: Vec<&str> = sections[0].split(" ").collect();
+        if sections.len() != 2 || first.len() != 5 {
+            return Err(format!("expected \"Valve XX has flow rate=N; tunnels lead to valves ...\" but found \"{}\"", line));
+        }
+        let rate = first[4].strip_prefix("rate=").ok_or(format!("expected rate= in \"{}\"", line))?;
+        Ok(Valve {
+            name: first[1].to_string(),
+            rate: parse_number(rate)?,
+            tunnels: sections[1].split(" ").skip(4).map(|s| s.trim_end_matches(',').to_string()).collect()
+        })
     }
 }
 
 fn best_simulation(valves: &HashMap<String, Valve>, current: &str, unopened: &HashSet<String>, remaining: usize, cache: &mut HashMap<(String, String),usize>) -> usize {
-    if remaining == 0 || unopened.len() == 0 {
+    if remaining == 0 || unopened.is_empty() {
         return 0;
     }
     let mut best_option = 0;
@@ -106,4 +128,24 @@ fn best_case(valves: &HashMap<String, Valve>, unopened: &HashSet<String>, minute
         }
     }
     sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day16.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day16.part1(&input), "1651");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day16.part2(&input), "1707");
+    }
 }
\ No newline at end of file
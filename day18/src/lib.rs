@@ -0,0 +1,108 @@
+use aoc_common::{Bounds3, ParseError, Point3, Solver};
+use aoc_common::input::{parse_lines_with, parse_number};
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = HashSet<Point3>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let points: Self::Input = parse_lines_with(text, parse_point)?.into_iter().collect();
+        if points.is_empty() {
+            return Err(ParseError::new(1, "expected at least one cube".to_string()));
+        }
+        Ok(points)
+    }
+
+    fn part1(&self, points: &Self::Input) -> String {
+        format!("{}", 6*points.len()-covered_sides(points))
+    }
+
+    fn part2(&self, points: &Self::Input) -> String {
+        let mut points = points.clone();
+        for air in find_pockets(&points) {
+            points.insert(air);
+        }
+        format!("{}", 6*points.len()-covered_sides(&points))
+    }
+}
+
+fn parse_point(text: &str) -> Result<Point3, String> {
+    let coordinates: Vec<&str> = text.split(",").collect();
+    if coordinates.len() != 3 {
+        Err(format!("expected 3 coordinates separated by commas in \"{}\"", text))
+    } else {
+        Ok(Point3 {
+            x: parse_number(coordinates[0])?,
+            y: parse_number(coordinates[1])?,
+            z: parse_number(coordinates[2])?
+        })
+    }
+}
+
+fn covered_sides(points: &HashSet<Point3>) -> usize {
+    points.iter().flat_map(|p| p.neighbours6()).filter(|a| points.contains(a)).count()
+}
+
+fn find_pockets(points: &HashSet<Point3>) -> HashSet<Point3> {
+    let mut pockets = HashSet::new();
+    let bounds = Bounds3::from_points(points).unwrap();
+    for p in bounds.points() {
+        if !points.contains(&p) && !pockets.contains(&p) {
+            let mut visited = HashSet::new();
+            if is_sealed(&p, points, &mut visited, &bounds) {
+                for v in visited {
+                    pockets.insert(v);
+                }
+            }
+        }
+    }
+    pockets
+}
+
+fn is_sealed(p: &Point3, points: &HashSet<Point3>, visited: &mut HashSet<Point3>, bounds: &Bounds3) -> bool {
+    if !bounds.contains(p) {
+        // reached the edge
+        return false;
+    }
+    if !visited.insert(*p) {
+        // already been here
+        return true;
+    }
+    for a in p.neighbours6() {
+        if !points.contains(&a) && !is_sealed(&a, points, visited, bounds) {
+            // found a way to the edge
+            return false;
+        }
+    }
+    // found something on every side
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day18.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day18.part1(&input), "64");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day18.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day18.part2(&input), "58");
+    }
+
+    #[test]
+    fn rejects_missing_cubes() {
+        let error = Day18.parse("").err().map(|e| e.to_string());
+        assert_eq!(error, Some("1: expected at least one cube".to_string()));
+    }
+}
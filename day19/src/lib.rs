@@ -0,0 +1,320 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{parse_lines, parse_number};
+use std::str::FromStr;
+use std::collections::HashMap;
+
+#[derive(Hash, Eq, PartialEq, Copy, Clone)]
+pub struct Blueprint {
+    ore_per_ore_robot: usize,
+    ore_per_clay_robot: usize,
+    ore_per_obsidian_robot: usize,
+    clay_per_obsidian_robot: usize,
+    ore_per_geode_robot: usize,
+    obsidian_per_geode_robot: usize
+}
+
+#[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
+struct State {
+    minutes_remaining: usize,
+    ore: usize,
+    ore_robots: usize,
+    clay: usize,
+    clay_robots: usize,
+    obsidian: usize,
+    obsidian_robots: usize,
+    geodes: usize,
+    geode_robots: usize,
+    passed_up: [bool; 4] // robots which could have been built last minute, but weren't
+}
+
+#[derive(Copy, Clone)]
+enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode
+}
+
+// the whole process peaked at around 140MB for the real input in a release build, so it only limits unusual blueprints
+const CACHE_CAPACITY: usize = 4000000;
+
+// the blueprints the elephants haven't eaten, for part 2
+const PART2_BLUEPRINTS: usize = 3;
+
+#[derive(Default)]
+pub struct Day19 {
+    pub blueprint: Option<usize>,
+    pub minutes: Option<usize>
+}
+
+impl Solver for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let blueprints: Vec<Blueprint> = parse_lines(text)?;
+        if let Some(b) = self.blueprint.filter(|b| *b > blueprints.len()) {
+            return Err(ParseError::new(blueprints.len().max(1), format!("expected blueprint {} but there are only {}", b, blueprints.len())));
+        }
+        Ok(blueprints)
+    }
+
+    fn warnings(&self, _: &Self::Input) -> Vec<ParseError> {
+        match self.blueprint.filter(|b| *b > PART2_BLUEPRINTS) {
+            Some(b) => vec![ParseError::new(b, format!("part 2 only uses the first {} blueprints, so has no answer for blueprint {}", PART2_BLUEPRINTS, b))],
+            None => Vec::new()
+        }
+    }
+
+    fn part1(&self, blueprints: &Self::Input) -> String {
+        let minutes = self.minutes.unwrap_or(24);
+        let mut sum = 0;
+        for (i, bp) in blueprints.iter().enumerate() {
+            if self.blueprint.is_none_or(|b| b == i + 1) {
+                let geodes = max_geodes(bp, State::new(minutes), &mut Cache::new(minutes, CACHE_CAPACITY));
+                sum += (i+1) * geodes;
+            }
+        }
+        format!("{}", sum)
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> String {
+        if self.blueprint.is_some_and(|b| b > PART2_BLUEPRINTS) {
+            return "none".to_string();
+        }
+        let minutes = self.minutes.unwrap_or(32);
+        let mut product = 1;
+        for (i, bp) in blueprints.iter().enumerate().take(PART2_BLUEPRINTS) {
+            if self.blueprint.is_none_or(|b| b == i + 1) {
+                let geodes = max_geodes(bp, State::new(minutes), &mut Cache::new(minutes, CACHE_CAPACITY));
+                product *= geodes;
+            }
+        }
+        format!("{}", product)
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        //Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+        let words: Vec<&str> = line.split(" ").collect();
+        if words.len() != 32 {
+            Err(format!("expected 32 words in \"{}\"", line))
+        } else {
+            Ok(Blueprint {
+                ore_per_ore_robot: parse_number(words[6])?,
+                ore_per_clay_robot: parse_number(words[12])?,
+                ore_per_obsidian_robot: parse_number(words[18])?,
+                clay_per_obsidian_robot: parse_number(words[21])?,
+                ore_per_geode_robot: parse_number(words[27])?,
+                obsidian_per_geode_robot: parse_number(words[30])?
+            })
+        }
+    }
+}
+
+impl Blueprint {
+    fn max_ore_cost(&self) -> usize {
+        self.ore_per_ore_robot.max(self.ore_per_clay_robot).max(self.ore_per_obsidian_robot).max(self.ore_per_geode_robot)
+    }
+}
+
+impl State {
+    fn new(minutes: usize) -> Self {
+        State {
+            minutes_remaining: minutes,
+            ore: 0,
+            ore_robots: 1,
+            clay: 0,
+            clay_robots: 0,
+            obsidian: 0,
+            obsidian_robots: 0,
+            geodes: 0,
+            geode_robots: 0,
+            passed_up: [false; 4]
+        }
+    }
+}
+
+// The best number of geodes reachable from each state of a single blueprint, layered by minutes remaining so that
+// once it reaches capacity the states closest to the end (which are the cheapest to recalculate) are evicted first
+struct Cache {
+    layers: Vec<HashMap<State, usize>>,
+    len: usize,
+    capacity: usize
+}
+
+impl Cache {
+    fn new(minutes: usize, capacity: usize) -> Self {
+        Cache {
+            layers: (0..(minutes+1)).map(|_| HashMap::new()).collect(),
+            len: 0,
+            capacity
+        }
+    }
+
+    fn get(&self, state: &State) -> Option<usize> {
+        self.layers.get(state.minutes_remaining)?.get(state).copied()
+    }
+
+    fn insert(&mut self, state: State, geodes: usize) {
+        if self.len >= self.capacity {
+            self.evict();
+        }
+        if self.layers[state.minutes_remaining].insert(state, geodes).is_none() {
+            self.len += 1;
+        }
+    }
+
+    // drops whole layers, from the fewest minutes remaining up, until at most half full
+    fn evict(&mut self) {
+        for layer in self.layers.iter_mut() {
+            if self.len <= self.capacity / 2 {
+                break;
+            }
+            self.len -= layer.len();
+            *layer = HashMap::new();
+        }
+    }
+}
+
+fn max_geodes(bp: &Blueprint, initial_state: State, cache: &mut Cache) -> usize {
+    if let Some(existing) = cache.get(&initial_state) {
+        existing
+    } else {
+        let mut state = initial_state;
+        let mut affordable: Vec<Robot> = Vec::new();
+        if state.ore >= bp.ore_per_geode_robot && state.obsidian >= bp.obsidian_per_geode_robot {
+            affordable.push(Robot::Geode);
+        }
+        // only one robot is built each minute, so there's no point making more of a resource than any robot costs
+        if state.ore >= bp.ore_per_obsidian_robot && state.clay >= bp.clay_per_obsidian_robot && state.obsidian_robots < bp.obsidian_per_geode_robot {
+            affordable.push(Robot::Obsidian);
+        }
+        if state.ore >= bp.ore_per_clay_robot && state.clay_robots < bp.clay_per_obsidian_robot {
+            affordable.push(Robot::Clay);
+        }
+        if state.ore >= bp.ore_per_ore_robot && state.ore_robots < bp.max_ore_cost() {
+            affordable.push(Robot::Ore);
+        }
+        // building a robot which was passed up last minute is never better than having built it then
+        let mut options: Vec<Option<Robot>> = affordable.iter().filter(|r| !state.passed_up[**r as usize]).map(|r| Some(*r)).collect();
+        options.push(None);
+        state.ore += state.ore_robots;
+        state.clay += state.clay_robots;
+        state.obsidian += state.obsidian_robots;
+        state.geodes += state.geode_robots;
+        state.minutes_remaining -= 1;
+        // likewise, any more than could be spent in the time remaining are as good as wasted, and would only fill the cache
+        state.ore = state.ore.min(bp.max_ore_cost() * state.minutes_remaining);
+        state.clay = state.clay.min(bp.clay_per_obsidian_robot * state.minutes_remaining);
+        state.obsidian = state.obsidian.min(bp.obsidian_per_geode_robot * state.minutes_remaining);
+        //println!("{} min remaining: {} ore [{}], {} clay [{}], {} obsidian [{}], {} geodes [{}] => {} options", state.minutes_remaining, state.ore, state.ore_robots, state.clay, state.clay_robots, state.obsidian, state.obsidian_robots, state.geodes, state.geode_robots, options.len());
+        let final_result = if state.minutes_remaining == 0 {
+            state.geodes
+        } else {
+            let mut best = 0;
+            for option in &options {
+                let mut new_state = state;
+                new_state.passed_up = [false; 4];
+                if option.is_none() {
+                    for robot in &affordable {
+                        new_state.passed_up[*robot as usize] = true;
+                    }
+                }
+                if let Some(new_robot) = option {
+                    match new_robot {
+                        Robot::Geode => {
+                            new_state.ore -= bp.ore_per_geode_robot;
+                            new_state.obsidian -= bp.obsidian_per_geode_robot;
+                            new_state.geode_robots += 1;
+                        },
+                        Robot::Obsidian => {
+                            new_state.ore -= bp.ore_per_obsidian_robot;
+                            new_state.clay -= bp.clay_per_obsidian_robot;
+                            new_state.obsidian_robots += 1;
+                        },
+                        Robot::Clay => {
+                            new_state.ore -= bp.ore_per_clay_robot;
+                            new_state.clay_robots += 1;
+                        },
+                        Robot::Ore => {
+                            new_state.ore -= bp.ore_per_ore_robot;
+                            new_state.ore_robots += 1;
+                        }
+                    }
+                }
+                if best_possible_geodes(&new_state) <= best {
+                    // don't bother
+                } else {
+                    let result = max_geodes(bp, new_state, cache);
+                    if result > best {
+                        best = result;
+                    }
+                }
+            }
+            best
+        };
+        cache.insert(initial_state, final_result);
+        final_result
+    }
+}
+
+
+
+fn best_possible_geodes(state: &State) -> usize {
+    //includes: existing geodes, geodes made by existing robotos, a new robot every minute making geodes
+    state.geodes + state.geode_robots * state.minutes_remaining + sum_of_ints_up_to(state.minutes_remaining - 1)
+}
+
+fn sum_of_ints_up_to(n: usize) -> usize {
+    // ie. triangular numbers
+    n*(n+1)/2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let solver = Day19::default();
+        let input = solver.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(solver.part1(&input), "33");
+    }
+
+    #[test]
+    fn checks_blueprint_exists() {
+        let error = Day19 { blueprint: Some(3), ..Day19::default() }.parse(&normalise(EXAMPLE)).err().map(|e| e.to_string());
+        assert_eq!(error, Some("2: expected blueprint 3 but there are only 2".to_string()));
+        let text = [EXAMPLE.trim_end(); 2].join("\n");
+        let solver = Day19 { blueprint: Some(4), minutes: Some(1) };
+        let input = solver.parse(&text).unwrap();
+        assert_eq!(solver.part2(&input), "none");
+        assert_eq!(solver.warnings(&input).len(), 1);
+    }
+
+    #[test]
+    fn evicts_without_changing_answers() {
+        // cheap enough to make a few geodes in a test's time
+        let bp: Blueprint = "Blueprint 1: Each ore robot costs 2 ore. Each clay robot costs 2 ore. Each obsidian robot costs 2 ore and 3 clay. Each geode robot costs 2 ore and 3 obsidian.".parse().unwrap();
+        let minutes = 14;
+        let unbounded = max_geodes(&bp, State::new(minutes), &mut Cache::new(minutes, usize::MAX));
+        assert_eq!(unbounded, 6);
+        let mut cache = Cache::new(minutes, 100);
+        assert_eq!(max_geodes(&bp, State::new(minutes), &mut cache), unbounded);
+        assert!(cache.len <= 100);
+    }
+
+    #[test]
+    #[ignore = "takes around 15 seconds in a release build"]
+    fn part2_example() {
+        let solver = Day19::default();
+        let input = solver.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(solver.part2(&input), "3472");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,399 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::parse_lines_with;
+
+// fixed so that simulations are repeatable
+const SEED: u64 = 2022;
+// changes to the guide listed by --details before the rest are just counted
+const CHANGES_SHOWN: usize = 10;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win
+}
+
+const OUTCOMES: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+pub struct Shape {
+    pub name: String,
+    pub theirs: char,
+    pub ours: char,
+    pub score: usize
+}
+
+impl Shape {
+    pub fn new(name: &str, theirs: char, ours: char, score: usize) -> Self {
+        Shape { name: name.to_string(), theirs, ours, score }
+    }
+}
+
+// The shapes which can be played, which of them beats which, and how each round is scored
+pub struct Rules {
+    pub shapes: Vec<Shape>,
+    // beats[a][b] is true when shape a beats shape b
+    beats: Vec<Vec<bool>>,
+    // our column's symbols when it is the outcome we need, in the order of OUTCOMES
+    pub outcomes: [char; 3],
+    pub outcome_scores: [usize; 3]
+}
+
+impl Rules {
+    pub fn new(shapes: Vec<Shape>, beats: &[(usize, usize)], outcomes: [char; 3], outcome_scores: [usize; 3]) -> Result<Self, String> {
+        let n = shapes.len();
+        let mut matrix = vec![vec![false; n]; n];
+        for (a, b) in beats {
+            if *a >= n || *b >= n {
+                return Err(format!("expected shapes below {} but found {} beats {}", n, a, b));
+            }
+            if a == b {
+                return Err(format!("{} cannot beat itself", shapes[*a].name));
+            }
+            matrix[*a][*b] = true;
+        }
+        for a in 0..n {
+            for b in 0..n {
+                if matrix[a][b] && matrix[b][a] {
+                    return Err(format!("{} and {} cannot both beat each other", shapes[a].name, shapes[b].name));
+                }
+            }
+            if shapes[(a + 1)..].iter().any(|s| s.theirs == shapes[a].theirs || s.ours == shapes[a].ours) {
+                return Err(format!("{} shares a symbol with another shape", shapes[a].name));
+            }
+            // every outcome must be possible against every shape, so that part 2 can always choose one
+            if !(0..n).any(|b| matrix[b][a]) || !(0..n).any(|b| matrix[a][b]) {
+                return Err(format!("{} must both beat and be beaten by another shape", shapes[a].name));
+            }
+        }
+        if let Some(c) = outcomes.iter().find(|c| !shapes.iter().any(|s| s.ours == **c)) {
+            return Err(format!("outcome symbol '{}' must also be one of our shapes", c));
+        }
+        Ok(Rules { shapes, beats: matrix, outcomes, outcome_scores })
+    }
+
+    // Each shape beats those an odd number of places before it (wrapping around), which is fair for any odd number of shapes
+    pub fn cyclic(shapes: Vec<Shape>) -> Result<Self, String> {
+        let n = shapes.len();
+        if n.is_multiple_of(2) {
+            return Err(format!("expected an odd number of shapes but found {}", n));
+        }
+        let mut beats = Vec::new();
+        for a in 0..n {
+            for offset in (1..n).step_by(2) {
+                beats.push((a, (a + n - offset) % n));
+            }
+        }
+        Rules::new(shapes, &beats, ['X', 'Y', 'Z'], [0, 3, 6])
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Rules::cyclic(vec![
+            Shape::new("Rock", 'A', 'X', 1),
+            Shape::new("Paper", 'B', 'Y', 2),
+            Shape::new("Scissors", 'C', 'Z', 3)
+        ]).expect("rock paper scissors is valid")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Rules::cyclic(vec![
+            Shape::new("Rock", 'A', 'V', 1),
+            Shape::new("Paper", 'B', 'W', 2),
+            Shape::new("Scissors", 'C', 'X', 3),
+            Shape::new("Spock", 'D', 'Y', 4),
+            Shape::new("Lizard", 'E', 'Z', 5)
+        ]).expect("rock paper scissors lizard spock is valid")
+    }
+
+    pub fn outcome(&self, them: usize, us: usize) -> Outcome {
+        if self.beats[us][them] {
+            Outcome::Win
+        } else if self.beats[them][us] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn score(&self, them: usize, us: usize) -> usize {
+        let outcome = self.outcome(them, us);
+        let index = OUTCOMES.iter().position(|o| *o == outcome).unwrap();
+        self.shapes[us].score + self.outcome_scores[index]
+    }
+
+    // the best scoring of our shapes which gets the outcome we need
+    pub fn choose(&self, them: usize, need: Outcome) -> usize {
+        (0..self.shapes.len())
+            .filter(|us| self.outcome(them, *us) == need)
+            .max_by_key(|us| self.score(them, *us))
+            .expect("every outcome is possible")
+    }
+
+    // the best scoring of our shapes against theirs, whatever the outcome
+    pub fn best(&self, them: usize) -> usize {
+        (0..self.shapes.len()).max_by_key(|us| self.score(them, *us)).expect("at least 1 shape")
+    }
+
+    // the outcome needed when our column is read as one, if it is one of the outcome symbols
+    pub fn need(&self, us: usize) -> Option<Outcome> {
+        self.outcomes.iter().position(|c| *c == self.shapes[us].ours).map(|index| OUTCOMES[index])
+    }
+
+    // the first round (numbered from 1) whose column part 2 cannot read as an outcome, with the symbol found there
+    pub fn unreadable(&self, rounds: &[(usize, usize)]) -> Option<(usize, char)> {
+        rounds.iter().position(|(_, column)| self.need(*column).is_none())
+            .map(|i| (i + 1, self.shapes[rounds[i].1].ours))
+    }
+
+    fn parse_round(&self, text: &str) -> Result<(usize, usize), String> {
+        let columns: Vec<char> = text.split(" ").map(parse_symbol).collect::<Result<_, _>>()?;
+        if columns.len() != 2 {
+            return Err(format!("expected 2 hands in \"{}\"", text));
+        }
+        let them = self.shapes.iter().position(|s| s.theirs == columns[0])
+            .ok_or(format!("unknown hand \"{}\"", columns[0]))?;
+        let us = self.shapes.iter().position(|s| s.ours == columns[1])
+            .ok_or(format!("unknown hand \"{}\"", columns[1]))?;
+        Ok((them, us))
+    }
+}
+
+// How the second column of the guide is played
+#[derive(Copy, Clone)]
+pub enum Strategy {
+    Shapes,
+    Outcomes
+}
+
+impl Strategy {
+    fn play(&self, rules: &Rules, them: usize, column: usize) -> usize {
+        match self {
+            Strategy::Shapes => column,
+            Strategy::Outcomes => rules.choose(them, rules.need(column).expect("checked by unreadable"))
+        }
+    }
+}
+
+// A round of the guide which would score more by playing a different shape
+#[derive(Debug, PartialEq)]
+pub struct Change {
+    pub round: usize,
+    pub from: usize,
+    pub to: usize,
+    pub gain: usize
+}
+
+// The highest score possible against these moves of theirs, with the rounds (numbered from 1) to change from the guide
+pub fn optimise(rules: &Rules, rounds: &[(usize, usize)], strategy: Strategy) -> (usize, Vec<Change>) {
+    let mut total = 0;
+    let mut changes = Vec::new();
+    for (i, (them, column)) in rounds.iter().enumerate() {
+        let played = strategy.play(rules, *them, *column);
+        let best = rules.best(*them);
+        let (score, best_score) = (rules.score(*them, played), rules.score(*them, best));
+        total += best_score;
+        if best_score > score {
+            changes.push(Change { round: i + 1, from: played, to: best, gain: best_score - score });
+        }
+    }
+    (total, changes)
+}
+
+pub struct Simulation {
+    pub mean: f64,
+    pub variance: f64
+}
+
+// Plays the guide's columns against an opponent choosing each shape at random, in proportion to its weight
+pub fn simulate(rules: &Rules, rounds: &[(usize, usize)], strategy: Strategy, weights: &[f64], games: usize) -> Simulation {
+    let mut random = Random(SEED);
+    let total_weight: f64 = weights.iter().sum();
+    let mut scores = Vec::new();
+    for _ in 0..games {
+        let mut score = 0;
+        for (_, column) in rounds {
+            let mut pick = random.next_f64() * total_weight;
+            let mut them = weights.len() - 1;
+            for (shape, weight) in weights.iter().enumerate() {
+                if pick < *weight {
+                    them = shape;
+                    break;
+                }
+                pick -= weight;
+            }
+            score += rules.score(them, strategy.play(rules, them, *column));
+        }
+        scores.push(score as f64);
+    }
+    let mean = scores.iter().sum::<f64>() / games as f64;
+    let variance = scores.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / games as f64;
+    Simulation { mean, variance }
+}
+
+// splitmix64, which is plenty random enough for simulating an elf
+struct Random(u64);
+
+impl Random {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+pub struct Day2 {
+    pub rules: Rules,
+    // the relative chance of the opponent playing each shape in simulations, or all equally likely if None
+    pub opponent: Option<Vec<f64>>,
+    pub games: usize
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Day2 {
+            rules: Rules::rock_paper_scissors(),
+            opponent: None,
+            games: 1000
+        }
+    }
+}
+
+impl Solver for Day2 {
+    // the index of their shape and of the shape in our column
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        parse_lines_with(text, |line| self.rules.parse_round(line))
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> String {
+        let scores: Vec<usize> = rounds.iter().map(|(them, us)| self.rules.score(*them, *us)).collect();
+        format!("{}", scores.iter().sum::<usize>())
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> String {
+        // with more shapes than outcomes, our column may hold a shape which part 2 cannot read
+        if self.rules.unreadable(rounds).is_some() {
+            return "none".to_string();
+        }
+        let scores: Vec<usize> = rounds.iter().map(|(them, column)| {
+            let us = Strategy::Outcomes.play(&self.rules, *them, *column);
+            self.rules.score(*them, us)
+        }).collect();
+        format!("{}", scores.iter().sum::<usize>())
+    }
+
+    fn warnings(&self, rounds: &Self::Input) -> Vec<ParseError> {
+        match self.rules.unreadable(rounds) {
+            Some((round, symbol)) => vec![ParseError::new(round, format!("part 2 needs one of the outcomes {:?} but found \"{}\", so has no answer", self.rules.outcomes, symbol))],
+            None => Vec::new()
+        }
+    }
+
+    fn details(&self, rounds: &Self::Input) -> Option<String> {
+        let mut lines = Vec::new();
+        let weights = self.opponent.clone().unwrap_or(vec![1.0; self.rules.shapes.len()]);
+        let total_weight: f64 = weights.iter().sum();
+        let chances: Vec<String> = self.rules.shapes.iter().zip(&weights).map(|(s, w)| format!("{} {:.0}%", s.name, 100.0 * w / total_weight)).collect();
+        for (part, strategy) in [(1, Strategy::Shapes), (2, Strategy::Outcomes)] {
+            if let (Strategy::Outcomes, Some((round, _))) = (strategy, self.rules.unreadable(rounds)) {
+                lines.push(format!("Part {}: cannot read round {} as an outcome", part, round));
+                continue;
+            }
+            let (best, changes) = optimise(&self.rules, rounds, strategy);
+            lines.push(format!("Part {}: at best {} by changing {} rounds", part, best, changes.len()));
+            for change in changes.iter().take(CHANGES_SHOWN) {
+                lines.push(format!("  round {}: play {} not {} (+{})", change.round, self.rules.shapes[change.to].name, self.rules.shapes[change.from].name, change.gain));
+            }
+            if changes.len() > CHANGES_SHOWN {
+                lines.push(format!("  and {} more", changes.len() - CHANGES_SHOWN));
+            }
+            let simulation = simulate(&self.rules, rounds, strategy, &weights, self.games);
+            lines.push(format!("  against {} over {} games: mean {:.1}, variance {:.1}", chances.join(", "), self.games, simulation.mean, simulation.variance));
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+fn parse_symbol(hand: &str) -> Result<char, String> {
+    let mut chars = hand.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(format!("expected a single character hand but found \"{}\"", hand))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day2::default().part1(&input), "15");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day2::default().part2(&input), "12");
+    }
+
+    #[test]
+    fn plays_lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let [rock, paper, scissors, spock, lizard] = [0, 1, 2, 3, 4];
+        // always from our point of view, playing the second shape
+        assert_eq!(rules.outcome(spock, lizard), Outcome::Win);
+        assert_eq!(rules.outcome(paper, spock), Outcome::Lose);
+        assert_eq!(rules.outcome(lizard, rock), Outcome::Win);
+        assert_eq!(rules.outcome(scissors, spock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, rock), Outcome::Draw);
+        // against rock, both paper (2) and spock (4) win, so spock is chosen
+        assert_eq!(rules.choose(rock, Outcome::Win), spock);
+        // our rock (V) is not an outcome, so only part 2 cannot read it
+        let day2 = Day2 { rules, ..Day2::default() };
+        let input = day2.parse("E V\nA Z").unwrap();
+        assert_eq!(input, vec![(lizard, rock), (rock, lizard)]);
+        // rock crushes lizard (1 + 6), lizard is crushed by rock (5 + 0)
+        assert_eq!(day2.part1(&input), "12");
+        assert_eq!(day2.part2(&input), "none");
+        let warnings: Vec<String> = day2.warnings(&input).iter().map(|w| w.to_string()).collect();
+        assert_eq!(warnings, vec!["1: part 2 needs one of the outcomes ['X', 'Y', 'Z'] but found \"V\", so has no answer"]);
+    }
+
+    #[test]
+    fn rejects_unfair_rules() {
+        let shapes = || vec![Shape::new("Rock", 'A', 'X', 1), Shape::new("Paper", 'B', 'Y', 2)];
+        assert!(Rules::cyclic(shapes()).is_err());
+        assert!(Rules::new(shapes(), &[(0, 1), (1, 0)], ['X', 'Y', 'X'], [0, 3, 6]).is_err());
+    }
+
+    #[test]
+    fn optimises_example() {
+        let day2 = Day2::default();
+        let input = day2.parse(&normalise(EXAMPLE)).unwrap();
+        let (best, changes) = optimise(&day2.rules, &input, Strategy::Shapes);
+        // paper against rock, scissors against paper and rock against scissors
+        assert_eq!(best, 8 + 9 + 7);
+        assert_eq!(changes, vec![Change { round: 2, from: 0, to: 2, gain: 8 }, Change { round: 3, from: 2, to: 0, gain: 1 }]);
+    }
+
+    #[test]
+    fn simulates_certain_opponent() {
+        let day2 = Day2::default();
+        let input = day2.parse(&normalise(EXAMPLE)).unwrap();
+        // an opponent who always plays rock makes every game the same
+        let simulation = simulate(&day2.rules, &input, Strategy::Shapes, &[1.0, 0.0, 0.0], 100);
+        assert_eq!(simulation.mean, (8 + 4 + 3) as f64);
+        assert_eq!(simulation.variance, 0.0);
+    }
+}
\ No newline at end of file
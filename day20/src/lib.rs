@@ -0,0 +1,129 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{parse_lines_with, parse_number};
+
+#[derive(Default)]
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = Vec<isize>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let numbers: Vec<isize> = parse_lines_with(text, parse_number)?;
+        // each number moves around the others, and the answers are counted on from the 0
+        if numbers.len() < 2 {
+            return Err(ParseError::new(1, format!("expected at least 2 numbers but found {}", numbers.len())));
+        }
+        if !numbers.contains(&0) {
+            return Err(ParseError::new(1, "expected one of the numbers to be 0".to_string()));
+        }
+        Ok(numbers)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> String {
+        let (a, b, c) = run_mixing(numbers, 1, 1);
+        format!("{}", a+b+c)
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> String {
+        let (a, b, c) = run_mixing(numbers, 10, 811589153);
+        format!("{}", a+b+c)
+    }
+}
+
+fn move_position(positions: &mut [usize], index: usize, from: usize, to: usize) {
+    //println!("Move from pos {} to pos {}", from, to);
+    if from < to {
+        // move forward
+        for p in positions.iter_mut() {
+            if *p > from && *p <= to {
+                *p -= 1;
+            }
+        }
+        positions[index] = to;
+    } else if from > to {
+        // move backwards
+        for p in positions.iter_mut() {
+            if *p < from && *p >= to {
+                *p += 1;
+            }
+        }
+        positions[index] = to;
+    } else {
+        // equal, no change
+    }
+}
+
+fn get_by_position(positions: &[usize], numbers: &[isize], position: usize) -> isize {
+    let actual_position = position % numbers.len();
+    for i in 0..positions.len() {
+        if positions[i] == actual_position {
+            return numbers[i];
+        }
+    }
+    panic!("Not found");
+}
+
+fn run_mixing(original_numbers: &[isize], rounds: usize, factor: isize) -> (isize, isize, isize) {
+    let numbers: Vec<isize> = original_numbers.iter().map(|n| n * factor).collect();
+    let mut positions: Vec<usize> = Vec::new();
+    let mut zero_index = 0;
+    for (i, n) in numbers.iter().enumerate() {
+        positions.push(i);
+        if *n == 0 {
+            zero_index = i;
+        }
+    }
+    let loop_size = numbers.len() as isize - 1; // because being in the last place is the same as the first
+    for _ in 0..rounds {
+        for i in 0..numbers.len() {
+            let start = positions[i];
+            let mut finish = positions[i] as isize + numbers[i] % loop_size;
+            while finish < 0 {
+                finish += loop_size;
+            }
+            while finish >= numbers.len() as isize {
+                finish -= loop_size;
+            }
+            move_position(&mut positions, i, start, finish as usize);
+        }
+    }
+    let zero_position = positions[zero_index];
+    let a = get_by_position(&positions, &numbers, zero_position + 1000);
+    let b = get_by_position(&positions, &numbers, zero_position + 2000);
+    let c = get_by_position(&positions, &numbers, zero_position + 3000);
+    (a,b,c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day20.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day20.part1(&input), "3");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day20.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day20.part2(&input), "1623178306");
+    }
+
+    #[test]
+    fn finds_grove_coordinates() {
+        let numbers = Day20.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(run_mixing(&numbers, 1, 1), (4, -3, 2));
+        assert_eq!(run_mixing(&numbers, 10, 811589153), (811589153, 2434767459, -1623178306));
+    }
+
+    #[test]
+    fn rejects_unmixable_numbers() {
+        let error = |text| Day20.parse(text).err().map(|e| e.to_string());
+        assert_eq!(error(""), Some("1: expected at least 2 numbers but found 0".to_string()));
+        assert_eq!(error("1\n2"), Some("1: expected one of the numbers to be 0".to_string()));
+    }
+}
@@ -1,9 +1,9 @@
-use std::env;
-use std::fs;
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{parse_lines, parse_number};
 use std::str::FromStr;
 use std::collections::HashMap;
 
-struct Assignment {
+pub struct Assignment {
     name: String,
     expression: Expression
 }
@@ -19,30 +19,46 @@ enum Operation {
     Division
 }
 
-const NL: &str = "\r\n";
+#[derive(Default)]
+pub struct Day21;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
+impl Solver for Day21 {
+    type Input = HashMap<String, Assignment>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let list: Vec<Assignment> = parse_lines(text)?;
+        for (i, assignment) in list.iter().enumerate() {
+            if let Expression::Algebra(a, _, b) = &assignment.expression {
+                if let Some(missing) = [a, b].into_iter().find(|n| !list.iter().any(|other| other.name == **n)) {
+                    return Err(ParseError::new(i + 1, format!("monkey {} is not defined", missing)));
+                }
+            }
+        }
+        match list.iter().position(|a| a.name == "root") {
+            None => return Err(ParseError::new(1, "monkey root is not defined".to_string())),
+            Some(i) if matches!(list[i].expression, Expression::Literal(_)) => return Err(ParseError::new(i + 1, "root should be an operation".to_string())),
+            _ => {}
+        }
         let mut assignments: HashMap<String, Assignment> = HashMap::new();
-        for assignment in text.split(NL).map(|s| s.parse::<Assignment>().unwrap()) {
+        for assignment in list {
             assignments.insert(assignment.name.to_string(), assignment);
         }
-        println!("Root value: {}", assignments["root"].calculate(&assignments));
-        // part2
-        assignments.insert("root".to_string(), match &assignments["root"].expression {
+        Ok(assignments)
+    }
+
+    fn part1(&self, assignments: &Self::Input) -> String {
+        format!("{}", assignments["root"].calculate(assignments))
+    }
+
+    fn part2(&self, assignments: &Self::Input) -> String {
+        let root = match &assignments["root"].expression {
             Expression::Literal(_) => panic!("Root should have been an operation"),
             Expression::Algebra(a, _, b) => Assignment {
                 name: "root".to_string(),
                 expression: Expression::Algebra(a.to_string(), Operation::Difference, b.to_string())
             }
-        });
-        println!("My value: {}", assignments["root"].goal_find(0, &assignments, "humn"));
-    } else {
-        println!("Please provide 1 argument: Filename");
+        };
+        format!("{}", root.goal_find(0, assignments, "humn"))
     }
 }
 
@@ -53,11 +69,11 @@ impl FromStr for Expression {
         //Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
         let words: Vec<&str> = segment.split(" ").collect();
         if words.len() == 1 {
-            Ok(Expression::Literal(words[0].parse().unwrap()))
+            Ok(Expression::Literal(parse_number(words[0])?))
         } else if words.len() == 3 {
             let a = words[0].to_string();
             let b = words[2].to_string();
-            let operation = words[1].parse().unwrap();
+            let operation = words[1].parse()?;
             Ok(Expression::Algebra(a, operation, b))
         } else {
             Err(format!("Should have 1 or 3 words: {}", segment))
@@ -89,7 +105,7 @@ impl FromStr for Assignment {
         } else {
             Ok(Assignment {
                 name: segments[0].to_string(),
-                expression: segments[1].parse().unwrap()
+                expression: segments[1].parse()?
             })
         }
     }
@@ -162,4 +178,24 @@ impl Assignment {
             }
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day21.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day21.part1(&input), "152");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day21.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day21.part2(&input), "301");
+    }
 }
\ No newline at end of file
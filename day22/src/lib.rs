@@ -1,5 +1,5 @@
-use std::env;
-use std::fs;
+use aoc_common::{Direction, ParseError, Solver};
+use aoc_common::input::{lines, numbered_blocks};
 
 struct Position {
     row: usize,
@@ -7,22 +7,14 @@ struct Position {
     facing: Direction
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-enum Direction {
-    Right,
-    Down,
-    Left,
-    Up
-}
-
-enum Instruction {
+pub enum Instruction {
     Move(usize),
     TurnLeft,
     TurnRight
 }
 
 #[derive(PartialEq, Copy, Clone)]
-enum Tile {
+pub enum Tile {
     None,
     Open,
     Blocked
@@ -34,69 +26,91 @@ enum WrapType {
     Cube2
 }
 
-const NL: &str = "\r\n";
-const NLNL: &str = "\r\n\r\n";
+#[derive(Default)]
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = (Vec<Vec<Tile>>, Vec<Instruction>);
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let sections: Vec<&str> = text.split(NLNL).collect();
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let sections: Vec<(usize, &str)> = numbered_blocks(text).collect();
         if sections.len() != 2 {
-            panic!("Expected 2 sections");
+            return Err(ParseError::new(1, format!("expected 2 sections (map then path) but found {}", sections.len())));
         }
-        let grid = parse_grid(sections[0]);
-        let instructions = parse_instructions(sections[1]);
-        println!("Grid: {}x{}", grid.len(), grid[0].len());
-        println!("Instructions: {}", instructions.len());
-        //part1
-        let mut pos = find_starting_position(&grid);
-        for instruction in &instructions {
-            instruction.process(&mut pos, &grid, &WrapType::Flat);
+        let grid = parse_grid(sections[0].1)?;
+        if !grid.first().is_some_and(|row| row.contains(&Tile::Open)) {
+            return Err(ParseError::new(1, "expected an open tile on the first row to start from".to_string()));
+        }
+        let (offset, path) = sections[1];
+        let instructions = parse_instructions(path).map_err(|e| e.offset(offset))?;
+        Ok((grid, instructions))
+    }
+
+    fn part1(&self, (grid, instructions): &Self::Input) -> String {
+        format!("{}", password(grid, instructions, &WrapType::Flat))
+    }
+
+    fn part2(&self, (grid, instructions): &Self::Input) -> String {
+        match cube_layout(grid) {
+            Some(wrap_type) => format!("{}", password(grid, instructions, &wrap_type)),
+            None => "none".to_string()
         }
-        println!("Part1 Final position: {},{} facing {:?}", pos.row, pos.column, pos.facing);
-        println!("Part1 Password: {}", 1000 * (pos.row + 1) + 4 * (pos.column + 1) + pos.facing.value());
-        //part2
-        let wrap_type = if grid.len() > grid[0].len() {
-            WrapType::Cube2
-        } else {
-            WrapType::Cube
-        };
-        // for r in 0..grid.len() {
-        //     let mut line = Vec::new();
-        //     for c in 0..grid[0].len() {
-        //         line.push(match grid[r][c] {
-        //             Tile::None => ' ',
-        //             Tile::Open => '.',
-        //             Tile::Blocked => '#'
-        //         });
-        //     }
-        //     println!("{}", line.iter().collect::<String>());
-        // }
-        let mut pos = find_starting_position(&grid);
-        for instruction in &instructions {
-            instruction.process(&mut pos, &grid, &wrap_type);
+    }
+
+    fn warnings(&self, (grid, _): &Self::Input) -> Vec<ParseError> {
+        match cube_layout(grid) {
+            Some(_) => Vec::new(),
+            None => vec![ParseError::new(1, "the map doesn't fold into a cube like the example or the real input, so part 2 has no answer".to_string())]
         }
-        println!("Part2 Final position: {},{} facing {:?}", pos.row, pos.column, pos.facing);
-        println!("Part2 Password: {}", 1000 * (pos.row + 1) + 4 * (pos.column + 1) + pos.facing.value());
+    }
+}
+
+// the faces of each cube net which part 2 can fold, as (row, column) in units of the cube's size
+const CUBE_FACES: [(usize, usize); 6] = [(0, 2), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)];
+const CUBE2_FACES: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+
+// which way part 2 folds the map, if it is one of the cube nets it knows how to
+fn cube_layout(grid: &[Vec<Tile>]) -> Option<WrapType> {
+    let (height, width) = (grid.len(), grid[0].len());
+    let (wrap_type, faces, cube) = if height * 3 == width * 4 {
+        (WrapType::Cube2, CUBE2_FACES, height / 4)
+    } else if height * 4 == width * 3 {
+        (WrapType::Cube, CUBE_FACES, height / 3)
     } else {
-        println!("Please provide 1 argument: Filename");
+        return None;
+    };
+    if cube == 0 {
+        return None;
+    }
+    for (r, row) in grid.iter().enumerate() {
+        for (c, tile) in row.iter().enumerate() {
+            if faces.contains(&(r / cube, c / cube)) == (*tile == Tile::None) {
+                return None;
+            }
+        }
+    }
+    Some(wrap_type)
+}
+
+fn password(grid: &[Vec<Tile>], instructions: &Vec<Instruction>, wrap_type: &WrapType) -> usize {
+    let mut pos = find_starting_position(grid);
+    for instruction in instructions {
+        instruction.process(&mut pos, grid, wrap_type);
     }
+    1000 * (pos.row + 1) + 4 * (pos.column + 1) + facing_value(&pos.facing)
 }
 
-fn parse_grid(text: &str) -> Vec<Vec<Tile>> {
+fn parse_grid(text: &str) -> Result<Vec<Vec<Tile>>, ParseError> {
     let mut grid = Vec::new();
     let mut max_length = 0;
-    for line in text.split(NL) {
+    for (y, line) in lines(text).enumerate() {
         let mut row = Vec::new();
-        for c in line.chars() {
+        for (x, c) in line.chars().enumerate() {
             row.push(match c {
                 '.' => Tile::Open,
                 '#' => Tile::Blocked,
                 ' ' => Tile::None,
-                _ => panic!("Invalid tile: {}", c)
+                _ => return Err(ParseError::new(y + 1, format!("expected a tile . # or space but found '{}'", c)).with_column(x + 1))
             });
         }
         if row.len() > max_length {
@@ -109,34 +123,43 @@ fn parse_grid(text: &str) -> Vec<Vec<Tile>> {
             row.push(Tile::None);
         }
     }
-    grid
+    Ok(grid)
 }
 
-fn parse_instructions(line: &str) -> Vec<Instruction> {
+// the path is a single line, so errors are reported on line 1 of this section
+fn parse_instructions(line: &str) -> Result<Vec<Instruction>, ParseError> {
+    if line.contains('\n') {
+        return Err(ParseError::new(2, "expected the path on a single line".to_string()));
+    }
     let mut instructions = Vec::new();
-    let mut current_number: Vec<char> = Vec::new();
-    for c in line.chars() {
+    let mut current_number: usize = 0;
+    let mut has_number = false;
+    for (i, c) in line.chars().enumerate() {
         match c {
-            'L' => {
-                instructions.push(Instruction::Move(current_number.iter().collect::<String>().parse().unwrap()));
-                current_number = Vec::new();
-                instructions.push(Instruction::TurnLeft);
-            },
-            'R' => {
-                instructions.push(Instruction::Move(current_number.iter().collect::<String>().parse().unwrap()));
-                current_number = Vec::new();
-                instructions.push(Instruction::TurnRight);
+            'L' | 'R' => {
+                if has_number {
+                    instructions.push(Instruction::Move(current_number));
+                }
+                current_number = 0;
+                has_number = false;
+                instructions.push(if c == 'L' { Instruction::TurnLeft } else { Instruction::TurnRight });
             },
-            _ => current_number.push(c)
+            _ => match c.to_digit(10) {
+                Some(digit) => {
+                    current_number = current_number * 10 + digit as usize;
+                    has_number = true;
+                },
+                None => return Err(ParseError::new(1, format!("expected a number, L or R but found '{}'", c)).with_column(i + 1))
+            }
         }
     }
-    if current_number.len() > 0 {
-        instructions.push(Instruction::Move(current_number.iter().collect::<String>().parse().unwrap()));
+    if has_number {
+        instructions.push(Instruction::Move(current_number));
     }
-    instructions
+    Ok(instructions)
 }
 
-fn find_starting_position(grid: &Vec<Vec<Tile>>) -> Position {
+fn find_starting_position(grid: &[Vec<Tile>]) -> Position {
     let mut pos = Position { row: 0, column: 0, facing: Direction::Right };
     while grid[pos.row][pos.column] != Tile::Open {
         pos.column += 1;
@@ -144,52 +167,23 @@ fn find_starting_position(grid: &Vec<Vec<Tile>>) -> Position {
     pos
 }
 
-impl Direction {
-    fn value(&self) -> usize {
-        match self {
-            Direction::Right => 0,
-            Direction::Down => 1,
-            Direction::Left => 2,
-            Direction::Up => 3
-        }
-    }
-
-    fn left(&self) -> Self {
-        match self {
-            Direction::Right => Direction::Up,
-            Direction::Down => Direction::Right,
-            Direction::Left => Direction::Down,
-            Direction::Up => Direction::Left
-        }
-    }
-
-    fn right(&self) -> Self {
-        match self {
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-            Direction::Up => Direction::Right
-        }
-    }
-
-    fn move_delta(&self) -> (isize, isize) { // row delta, column delta
-        match self {
-            Direction::Right => (0, 1),
-            Direction::Down => (1, 0),
-            Direction::Left => (0, -1),
-            Direction::Up => (-1, 0)
-        }
+fn facing_value(facing: &Direction) -> usize {
+    match facing {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3
     }
 }
 
 impl Instruction {
-    fn process(&self, position: &mut Position, grid: &Vec<Vec<Tile>>, wrap_type: &WrapType) {
+    fn process(&self, position: &mut Position, grid: &[Vec<Tile>], wrap_type: &WrapType) {
         match self {
-            Instruction::TurnLeft => position.facing = position.facing.left(),
-            Instruction::TurnRight => position.facing = position.facing.right(),
+            Instruction::TurnLeft => position.facing = position.facing.turn_left(),
+            Instruction::TurnRight => position.facing = position.facing.turn_right(),
             Instruction::Move(max) => {
                 for _ in 0..*max {
-                    let new_pos = wrap_type.wrap_position(&position, &grid);
+                    let new_pos = wrap_type.wrap_position(position, grid);
                     if grid[new_pos.row][new_pos.column] == Tile::Open {
                         *position = new_pos;
                     } else { // blocked
@@ -202,8 +196,9 @@ impl Instruction {
 }
 
 impl WrapType {
-    fn wrap_position(&self, position: &Position, grid: &Vec<Vec<Tile>>) -> Position {
-        let (dr, dc) = position.facing.move_delta();
+    fn wrap_position(&self, position: &Position, grid: &[Vec<Tile>]) -> Position {
+        let delta = position.facing.delta();
+        let (dr, dc) = (delta.y, delta.x);
         let mut r = position.row as isize + dr;
         let mut c = position.column as isize + dc;
         let mut f = position.facing;
@@ -341,7 +336,7 @@ impl WrapType {
                         } else {
                             // 6 -> 2
                             r = 4 * cube - 1;
-                            c = c - 2 * cube;
+                            c -= 2 * cube;
                             f = Direction::Up;
                         }
                     } else if c < 0 && f == Direction::Left {
@@ -358,7 +353,7 @@ impl WrapType {
                         }
                     } else if r == height && f == Direction::Down {
                         // 2 -> 6
-                        c = c + 2 * cube;
+                        c += 2 * cube;
                         r = 0;
                         f = Direction::Down;
                     } else if c == width && f == Direction::Right {
@@ -427,4 +422,33 @@ impl WrapType {
         }
         Position { row: r as usize, column: c as usize, facing: f }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day22.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day22.part1(&input), "6032");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day22.part2(&input), "5031");
+    }
+
+    #[test]
+    fn only_folds_cubes() {
+        let input = Day22.parse("..#\n\n10R5").unwrap();
+        assert_eq!(Day22.part1(&input), "1009");
+        assert_eq!(Day22.part2(&input), "none");
+        assert_eq!(Day22.warnings(&input).len(), 1);
+        assert!(Day22.warnings(&Day22.parse(&normalise(EXAMPLE)).unwrap()).is_empty());
+    }
+}
@@ -1,14 +1,8 @@
-use std::env;
-use std::fs;
+use aoc_common::{Bounds, ParseError, Point, Solver};
+use aoc_common::input::lines;
 use std::collections::HashSet;
 use std::collections::HashMap;
 
-#[derive(Hash, Eq, PartialEq, Copy, Clone)]
-struct Point {
-    x: isize,
-    y: isize
-}
-
 #[derive(PartialEq, Copy, Clone, Debug)]
 enum Direction {
     North,
@@ -17,69 +11,66 @@ enum Direction {
     East
 }
 
-const NL: &str = "\r\n";
+pub struct Day23 {
+    pub rounds: usize
+}
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 3 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let mut elves = load_elves(&text);
-        let mut next_direction = Direction::North;
-        let mut change: bool;
-        let rounds: usize = args[2].parse().unwrap();
-        println!("Before:");
-        _display_grid(&elves);
-        println!("");
-        for round in 1..(rounds+1) {
-            (elves, change) = process_round(&elves, &mut next_direction);
-            if !change {
-                // finished
-                println!("No moves required in round {}", round);
-                break;
-            }
-            let (min, _) = find_bounds(&elves);
-            println!("After round {}: ({},{})", round, min.x, min.y);
-            //_display_grid(&elves);
-            //println!("");
+impl Default for Day23 {
+    fn default() -> Self {
+        Day23 {
+            rounds: 10
         }
-        let size = find_size(&elves);
-        println!("{} in {}x{} grid with {} empty places", elves.len(), size.x, size.y, size.x*size.y-elves.len() as isize);
-    } else {
-        println!("Please provide 2 arguments: Filename, Rounds");
     }
 }
 
-fn find_bounds(points: &HashSet<Point>) -> (Point, Point) {
-    (Point {
-        x: points.iter().map(|p| p.x).min().unwrap(),
-        y: points.iter().map(|p| p.y).min().unwrap()
-    },
-    Point {
-        x: points.iter().map(|p| p.x).max().unwrap(),
-        y: points.iter().map(|p| p.y).max().unwrap()
-    })
-}
+impl Solver for Day23 {
+    type Input = HashSet<Point>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let elves = load_elves(text)?;
+        if elves.is_empty() {
+            return Err(ParseError::new(1, "expected at least one elf".to_string()));
+        }
+        Ok(elves)
+    }
 
-fn find_size(points: &HashSet<Point>) -> Point {
-    let (min, max) = find_bounds(points);
-    Point {
-        x: max.x - min.x + 1,
-        y: max.y - min.y + 1
+    fn part1(&self, elves: &Self::Input) -> String {
+        let mut elves = elves.clone();
+        let mut next_direction = Direction::North;
+        for _ in 0..self.rounds {
+            (elves, _) = process_round(&elves, &mut next_direction);
+        }
+        let bounds = Bounds::from_points(&elves).unwrap();
+        format!("{}", bounds.area()-elves.len() as isize)
+    }
+
+    fn part2(&self, elves: &Self::Input) -> String {
+        let mut elves = elves.clone();
+        let mut next_direction = Direction::North;
+        let mut change = true;
+        let mut round = 0;
+        while change {
+            (elves, change) = process_round(&elves, &mut next_direction);
+            round += 1;
+        }
+        format!("{}", round)
     }
 }
 
-fn load_elves(text: &str) -> HashSet<Point> {
+fn load_elves(text: &str) -> Result<HashSet<Point>, ParseError> {
     let mut points = HashSet::new();
-    for (y, line) in text.split(NL).enumerate() {
+    for (y, line) in lines(text).enumerate() {
         for (x, c) in line.chars().enumerate() {
-            if c == '#' {
-                points.insert(Point { x: x as isize, y: y as isize });
+            match c {
+                '#' => {
+                    points.insert(Point { x: x as isize, y: y as isize });
+                },
+                '.' => {},
+                _ => return Err(ParseError::new(y + 1, format!("expected an elf # or ground . but found '{}'", c)).with_column(x + 1))
             }
         }
     }
-    points
+    Ok(points)
 }
 
 impl Direction {
@@ -92,22 +83,22 @@ impl Direction {
         }
     }
 
-    fn forward(&self, p: &Point) -> Point {
+    fn heading(&self) -> aoc_common::Direction {
         match self {
-            Direction::North => Point { x: p.x, y: p.y - 1 },
-            Direction::South => Point { x: p.x, y: p.y + 1 },
-            Direction::West => Point { x: p.x - 1, y: p.y },
-            Direction::East => Point { x: p.x + 1, y: p.y }
+            Direction::North => aoc_common::Direction::Up,
+            Direction::South => aoc_common::Direction::Down,
+            Direction::West => aoc_common::Direction::Left,
+            Direction::East => aoc_common::Direction::Right
         }
     }
 
+    fn forward(&self, p: &Point) -> Point {
+        *p + self.heading().delta()
+    }
+
     fn corners(&self, p: &Point) -> (Point, Point) {
-        match self {
-            Direction::North => (Point { x: p.x - 1, y: p.y - 1 },Point { x: p.x + 1, y: p.y - 1 }),
-            Direction::South => (Point { x: p.x - 1, y: p.y + 1 },Point { x: p.x + 1, y: p.y + 1 }),
-            Direction::West => (Point { x: p.x - 1, y: p.y - 1 },Point { x: p.x - 1, y: p.y + 1 }),
-            Direction::East => (Point { x: p.x + 1, y: p.y - 1 },Point { x: p.x + 1, y: p.y + 1 })
-        }
+        let forward = self.forward(p);
+        (forward + self.heading().turn_left().delta(), forward + self.heading().turn_right().delta())
     }
 }
 
@@ -125,20 +116,20 @@ fn process_round(old: &HashSet<Point>, direction: &mut Direction) -> (HashSet<Po
             //println!("({},{}) proposes move to ({},{})", old_p.x, old_p.y, new_p.x, new_p.y);
             if conflicts.contains(&new_p) {
                 // existing conflict, don't move this point
-                if moves.insert(*old_p, *old_p) != None {
+                if moves.insert(*old_p, *old_p).is_some() {
                     panic!("Double conflict not moving ({},{})", old_p.x, old_p.y);
                 }
                 panic!("This really was required after all");
             } else if let Some(conflict) = moves.insert(new_p, *old_p) {
                 // new conflict, don't move this point, and move the conflict back as well
                 //println!("Found conflict at ({},{}), therefore ({},{}) and ({},{}) don't move", new_p.x, new_p.y, old_p.x, old_p.y, conflict.x, conflict.y);
-                if moves.remove(&new_p) == None {
+                if moves.remove(&new_p).is_none() {
                     panic!("Failed to remove conflict");
                 }
-                if moves.insert(*old_p, *old_p) != None {
+                if moves.insert(*old_p, *old_p).is_some() {
                     panic!("Double conflict not moving ({},{})", old_p.x, old_p.y);
                 }
-                if moves.insert(conflict, conflict) != None {
+                if moves.insert(conflict, conflict).is_some() {
                     panic!("Double conflict not moving ({},{})", conflict.x, conflict.y);
                 }
                 conflicts.insert(new_p);
@@ -146,7 +137,7 @@ fn process_round(old: &HashSet<Point>, direction: &mut Direction) -> (HashSet<Po
         }
     }
     *direction = direction.next();
-    (moves.into_iter().map(|(k,_)| k).collect(), change)
+    (moves.into_keys().collect(), change)
 }
 
 fn propose_move(existing: &HashSet<Point>, p: &Point, first_direction: &Direction) -> Point {
@@ -173,19 +164,11 @@ fn propose_move(existing: &HashSet<Point>, p: &Point, first_direction: &Directio
 }
 
 fn clear_all_sides(existing: &HashSet<Point>, p: &Point) -> bool {
-    for x in (p.x-1)..(p.x+2) {
-        for y in (p.y-1)..(p.y+2) {
-            let check = Point { x, y };
-            if *p != check && existing.contains(&check) {
-                return false;
-            }
-        }
-    }
-    true
+    !p.neighbours8().any(|check| existing.contains(&check))
 }
 
 fn _display_grid(points: &HashSet<Point>) {
-    let (min, max) = find_bounds(points);
+    let Bounds { min, max } = Bounds::from_points(points).unwrap();
     for y in min.y..(max.y+1) {
         let mut line = Vec::new();
         for x in min.x..(max.x+1) {
@@ -197,4 +180,32 @@ fn _display_grid(points: &HashSet<Point>) {
         }
         println!("{}", line.iter().collect::<String>());
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test2.txt");
+
+    #[test]
+    fn part1_example() {
+        let solver = Day23::default();
+        let input = solver.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(solver.part1(&input), "110");
+    }
+
+    #[test]
+    fn part2_example() {
+        let solver = Day23::default();
+        let input = solver.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(solver.part2(&input), "20");
+    }
+
+    #[test]
+    fn rejects_missing_elves() {
+        let error = Day23::default().parse("...").err().map(|e| e.to_string());
+        assert_eq!(error, Some("1: expected at least one elf".to_string()));
+    }
+}
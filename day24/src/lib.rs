@@ -0,0 +1,163 @@
+use aoc_common::{Direction, Grid, ParseError, Point, Solver};
+use pathfinding::prelude::bfs;
+use std::str::FromStr;
+use std::collections::HashSet;
+
+pub struct Valley {
+    start: Point,
+    finish: Point,
+    tiles: Grid<Tile>,
+    blizzards: Vec<Blizzard>
+}
+
+struct Blizzard {
+    initial_position: Point,
+    direction: Direction
+}
+
+#[derive(PartialEq)]
+enum Tile {
+    Wall,
+    Open
+}
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+struct State {
+    position: Point,
+    minute: usize
+}
+
+impl Blizzard {
+    fn current_position(&self, minute: usize, valley: &Valley) -> Point {
+        let p = self.initial_position + self.direction.delta() * minute as isize;
+        Point {
+            x: (p.x - 1).rem_euclid(valley.tiles.width() as isize - 2) + 1,
+            y: (p.y - 1).rem_euclid(valley.tiles.height() as isize - 2) + 1
+        }
+    }
+}
+
+impl State {
+    fn successors(&self, valley: &Valley) -> Vec<Self> {
+        let mut options = Vec::new();
+        let mut state = self.clone();
+        state.minute += 1;
+        let blizzards: HashSet<Point> = valley.blizzards.iter().map(|b| b.current_position(state.minute, valley)).collect();
+        for p in self.position.neighbours4() {
+            if valley.tiles.get(&p) == Some(&Tile::Open) && !blizzards.contains(&p) {
+                let mut adjacent_state = state.clone();
+                adjacent_state.position = p;
+                options.push(adjacent_state);
+            }
+        }
+        if !blizzards.contains(&state.position) {
+            options.push(state);
+        }
+        options
+    }
+}
+
+impl FromStr for Valley {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chars: Grid<char> = Grid::parse(text, |ch| match ch {
+            '#' | '.' | '^' | 'v' | '>' | '<' => Ok(ch),
+            _ => Err(format!("expected a wall, ground or blizzard but found '{}'", ch))
+        })?;
+        if chars.height() < 2 {
+            return Err(ParseError::new(1, "expected at least 2 rows".to_string()));
+        }
+        let mut blizzards = Vec::new();
+        for (p, ch) in chars.iter() {
+            if let Some(direction) = match ch {
+                '^' => Some(Direction::Up),
+                'v' => Some(Direction::Down),
+                '>' => Some(Direction::Right),
+                '<' => Some(Direction::Left),
+                _ => None
+            } {
+                blizzards.push(Blizzard {
+                    initial_position: p,
+                    direction
+                });
+            }
+        }
+        let tiles = chars.map(|ch| match ch {
+            '#' => Tile::Wall,
+            _ => Tile::Open
+        });
+        Ok(Valley {
+            start: first_open(&tiles, 0)?,
+            finish: first_open(&tiles, tiles.height() - 1)?,
+            tiles,
+            blizzards
+        })
+    }
+}
+
+fn first_open(tiles: &Grid<Tile>, row: usize) -> Result<Point, ParseError> {
+    match tiles.row(row).iter().position(|tile| *tile == Tile::Open) {
+        Some(col) => Ok(Point { x: col as isize, y: row as isize }),
+        None => Err(ParseError::new(row + 1, "expected an open tile to enter or exit the valley".to_string()))
+    }
+}
+
+#[derive(Default)]
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Valley;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        text.parse()
+    }
+
+    fn part1(&self, valley: &Self::Input) -> String {
+        let state = State {
+            position: valley.start,
+            minute: 0
+        };
+        format!("{}", travel(valley, &state, &valley.finish).minute)
+    }
+
+    fn part2(&self, valley: &Self::Input) -> String {
+        let mut state = State {
+            position: valley.start,
+            minute: 0
+        };
+        state = travel(valley, &state, &valley.finish);
+        state = travel(valley, &state, &valley.start);
+        state = travel(valley, &state, &valley.finish);
+        format!("{}", state.minute)
+    }
+}
+
+fn travel(valley: &Valley, from: &State, to: &Point) -> State {
+    let path = bfs(
+        from,
+        |s| s.successors(valley),
+        |s| s.position == *to
+    ).unwrap();
+    path.last().unwrap().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day24.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day24.part1(&input), "18");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day24.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day24.part2(&input), "54");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::lines;
+
+fn decode(input: &str) -> isize {
+    input.chars().rev().enumerate().map(|(i, c)| 5_isize.pow(i as u32) * match c {
+        '=' => -2,
+        '-' => -1,
+        n => n as isize - '0' as isize
+    }).sum()
+}
+
+fn encode(input: isize) -> String {
+    let mut result = String::new();
+    let mut value = input;
+    while value > 0 {
+        let remainder = (value + 2).rem_euclid(5) - 2;
+        result.push(match remainder {
+            -2 => '=',
+            -1 => '-',
+            n => (n + '0' as isize) as u8 as char,
+        });
+        value = (value - remainder) / 5;
+    }
+    result.chars().rev().collect()
+}
+
+#[derive(Default)]
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Vec<isize>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let mut numbers = Vec::new();
+        for (y, line) in lines(text).enumerate() {
+            if let Some((x, c)) = line.chars().enumerate().find(|(_, c)| !"=-012".contains(*c)) {
+                return Err(ParseError::new(y + 1, format!("expected a SNAFU digit but found '{}'", c)).with_column(x + 1));
+            }
+            numbers.push(decode(line));
+        }
+        Ok(numbers)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> String {
+        encode(numbers.iter().sum())
+    }
+
+    fn part2(&self, _: &Self::Input) -> String {
+        "Merry Christmas".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day25.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day25.part1(&input), "2=-1=0");
+    }
+
+    #[test]
+    fn converts_snafu() {
+        for (n, snafu) in [(1, "1"), (3, "1="), (8, "2="), (20, "1-0"), (2022, "1=11-2"), (12345, "1-0---0"), (314159265, "1121-1110-1=0")] {
+            assert_eq!(encode(n), snafu);
+            assert_eq!(decode(snafu), n);
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for n in 1..1000 {
+            assert_eq!(decode(&encode(n)), n);
+        }
+    }
+}
\ No newline at end of file
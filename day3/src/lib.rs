@@ -0,0 +1,94 @@
+mod rucksack;
+
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::parse_lines_with;
+pub use rucksack::{badges, item, priority, Items, Rucksack};
+
+pub struct Day3 {
+    pub compartments: usize,
+    pub group_size: usize
+}
+
+impl Default for Day3 {
+    fn default() -> Self {
+        Day3 {
+            compartments: 2,
+            group_size: 3
+        }
+    }
+}
+
+impl Solver for Day3 {
+    type Input = Vec<Rucksack>;
+
+    // checks that every rucksack and group has exactly one item in common, so the parts can just add them up
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let rucksacks = parse_lines_with(text, |line| {
+            let rucksack = Rucksack::parse(line, self.compartments)?;
+            match rucksack.shared() {
+                shared if shared.len() == 1 => Ok(rucksack),
+                shared => Err(format!("expected 1 item in every compartment of \"{}\" but found {}", line, shared))
+            }
+        })?;
+        if !rucksacks.len().is_multiple_of(self.group_size) {
+            let last = rucksacks.len() - rucksacks.len() % self.group_size + 1;
+            return Err(ParseError::new(last, format!("expected groups of {} but the last has {}", self.group_size, rucksacks.len() % self.group_size)));
+        }
+        for (i, group) in rucksacks.chunks(self.group_size).enumerate() {
+            let badges = badges(group);
+            if badges.len() != 1 {
+                let first = i * self.group_size + 1;
+                return Err(ParseError::new(first, format!("expected 1 badge in the group of lines {}-{} but found {}", first, first + self.group_size - 1, badges)));
+            }
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> String {
+        let items: Vec<u32> = rucksacks.iter().flat_map(|r| r.shared().priorities()).collect();
+        format!("{}", items.iter().sum::<u32>())
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> String {
+        let badges: Vec<u32> = rucksacks.chunks(self.group_size).flat_map(|g| badges(g).priorities()).collect();
+        format!("{}", badges.iter().sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day3::default().part1(&input), "157");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day3::default().part2(&input), "70");
+    }
+
+    #[test]
+    fn finds_every_shared_item() {
+        let rucksack = Rucksack::parse("aCbCCcdC", 4).unwrap();
+        assert_eq!(rucksack.shared().items(), vec!['C']);
+        assert_eq!(Rucksack::parse("abCDabCD", 2).unwrap().shared().items(), vec!['a', 'b', 'C', 'D']);
+        assert_eq!(item(priority('Z').unwrap()), 'Z');
+    }
+
+    #[test]
+    fn rejects_ambiguous_input() {
+        let error = Day3::default().parse("abab\nvJrwpWtwJgWrhcsFMMfFFhFp").unwrap_err();
+        assert_eq!(error.to_string(), "1: expected 1 item in every compartment of \"abab\" but found 'a', 'b'");
+        let error = Day3::default().parse("vJrwpWtwJgWrhcsFMMfFFhFp\nvJrwpWtwJgWrhcsFMMfFFhFp").unwrap_err();
+        assert_eq!(error.to_string(), "1: expected groups of 3 but the last has 2");
+        let error = Day3::default().parse("aa\nbb\ncc").unwrap_err();
+        assert_eq!(error.to_string(), "1: expected 1 badge in the group of lines 1-3 but found nothing");
+    }
+}
\ No newline at end of file
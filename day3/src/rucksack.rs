@@ -0,0 +1,99 @@
+use std::fmt;
+
+// A set of item types, as one bit per priority (1 for 'a' up to 52 for 'Z')
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Items(u64);
+
+impl Items {
+    // every item type, to intersect down from
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut bits = 0;
+        for c in text.chars() {
+            let p = priority(c).ok_or(format!("unexpected item '{}' in \"{}\"", c, text))?;
+            bits |= 1 << p;
+        }
+        Ok(Items(bits))
+    }
+
+    pub fn intersect(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn priorities(&self) -> Vec<u32> {
+        (1..=52).filter(|p| self.0 & (1 << p) != 0).collect()
+    }
+
+    pub fn items(&self) -> Vec<char> {
+        self.priorities().into_iter().map(item).collect()
+    }
+}
+
+// eg. "'a', 'B'" or "nothing"
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "nothing");
+        }
+        let items: Vec<String> = self.items().iter().map(|c| format!("'{}'", c)).collect();
+        write!(f, "{}", items.join(", "))
+    }
+}
+
+pub fn priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 27),
+        _ => None
+    }
+}
+
+pub fn item(priority: u32) -> char {
+    if priority > 26 {
+        (b'A' + (priority - 27) as u8) as char
+    } else {
+        (b'a' + (priority - 1) as u8) as char
+    }
+}
+
+#[derive(Debug)]
+pub struct Rucksack {
+    pub compartments: Vec<Items>
+}
+
+impl Rucksack {
+    // splits the line into the given number of equally sized compartments
+    pub fn parse(line: &str, compartments: usize) -> Result<Self, String> {
+        // checked first so that every item is a single byte
+        Items::parse(line)?;
+        if !line.len().is_multiple_of(compartments) {
+            return Err(format!("expected a multiple of {} items in \"{}\"", compartments, line));
+        }
+        let size = line.len() / compartments;
+        let compartments = (0..compartments).map(|i| Items::parse(&line[(i * size)..((i + 1) * size)])).collect::<Result<_, _>>()?;
+        Ok(Rucksack { compartments })
+    }
+
+    pub fn contents(&self) -> Items {
+        Items(self.compartments.iter().fold(0, |all, c| all | c.0))
+    }
+
+    // the items in every compartment
+    pub fn shared(&self) -> Items {
+        self.compartments.iter().fold(Items::ALL, |shared, c| shared.intersect(*c))
+    }
+}
+
+// the items carried by every rucksack in the group
+pub fn badges(group: &[Rucksack]) -> Items {
+    group.iter().fold(Items::ALL, |shared, r| shared.intersect(r.contents()))
+}
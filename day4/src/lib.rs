@@ -0,0 +1,124 @@
+use aoc_common::{Interval, IntervalSet, ParseError, Solver};
+use aoc_common::input::{parse_lines, parse_number};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+pub struct Pair {
+    a: Interval,
+    b: Interval
+}
+
+#[derive(Default)]
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input = Vec<Pair>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(text)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> String {
+        let count = pairs.iter().filter(|p| p.a.contains(&p.b) || p.b.contains(&p.a)).count();
+        format!("{}", count)
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> String {
+        let count = pairs.iter().filter(|p| p.a.overlaps(&p.b)).count();
+        format!("{}", count)
+    }
+
+    fn details(&self, pairs: &Self::Input) -> Option<String> {
+        let assignments: Vec<Interval> = pairs.iter().flat_map(|p| [p.a, p.b]).collect();
+        let covered: IntervalSet = assignments.iter().copied().collect();
+        let bounds = covered.bounds()?;
+        let gaps: Vec<String> = covered.gaps(&bounds).iter().map(|g| g.to_string()).collect();
+        let wasted: usize = pairs.iter().filter_map(|p| p.a.intersection(&p.b)).map(|i| i.len()).sum();
+        let mut lines = Vec::new();
+        lines.push(format!("Sections {} are covered by {} assignments", bounds, assignments.len()));
+        lines.push(format!("Nobody covers {} sections: {}", bounds.len() - covered.len(), if gaps.is_empty() { "none".to_string() } else { gaps.join(", ") }));
+        lines.push(format!("Pairs overlap by {} sections in total", wasted));
+        if let Some((section, count)) = most_overlapped(&assignments) {
+            lines.push(format!("Most overlapped: sections {} by {} assignments", section, count));
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+// the first interval covered by the most assignments, and how many that is
+fn most_overlapped(assignments: &[Interval]) -> Option<(Interval, usize)> {
+    // the change in coverage at the start of each section
+    let mut changes: BTreeMap<isize, isize> = BTreeMap::new();
+    for a in assignments {
+        *changes.entry(a.start).or_default() += 1;
+        *changes.entry(a.end + 1).or_default() -= 1;
+    }
+    let mut best: Option<(Interval, usize)> = None;
+    let mut depth = 0;
+    let mut sections = changes.iter().peekable();
+    while let Some((start, change)) = sections.next() {
+        depth += change;
+        if let Some((next, _)) = sections.peek() {
+            if depth > 0 && best.is_none_or(|(_, most)| depth as usize > most) {
+                best = Some((Interval::new(*start, *next - 1), depth as usize));
+            }
+        }
+    }
+    best
+}
+
+impl FromStr for Pair {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let ranges: Vec<&str> = line.split(",").collect();
+        if ranges.len() != 2 {
+            return Err(format!("expected exactly one comma in \"{}\"", line));
+        }
+        Ok(Pair {
+            a: parse_interval(ranges[0])?,
+            b: parse_interval(ranges[1])?
+        })
+    }
+}
+
+fn parse_interval(text: &str) -> Result<Interval, String> {
+    let numbers: Vec<&str> = text.split("-").collect();
+    if numbers.len() != 2 {
+        return Err(format!("expected exactly one dash in \"{}\"", text));
+    }
+    let start: u32 = parse_number(numbers[0])?;
+    let end: u32 = parse_number(numbers[1])?;
+    if start > end {
+        return Err(format!("expected sections to end after they start in \"{}\"", text));
+    }
+    Ok(Interval::new(start as isize, end as isize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day4.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day4.part1(&input), "2");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day4.part2(&input), "4");
+    }
+
+    #[test]
+    fn finds_most_overlapped() {
+        let input = Day4.parse(&normalise(EXAMPLE)).unwrap();
+        let assignments: Vec<Interval> = input.iter().flat_map(|p| [p.a, p.b]).collect();
+        // only 2-4, 2-3, 4-5 and 7-9 miss section 6
+        assert_eq!(most_overlapped(&assignments), Some((Interval::new(6, 6), 8)));
+    }
+}
\ No newline at end of file
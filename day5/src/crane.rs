@@ -0,0 +1,122 @@
+// A model of crane, which decides which crates a move takes and the order they end up in
+pub trait Crane {
+    fn name(&self) -> String;
+
+    // removes count crates from the stack (which has at least that many), returning them in the order they are put down
+    fn lift(&self, stack: &mut Vec<char>, count: usize) -> Vec<char>;
+
+    // the opposite of lift, putting back crates in the order lift returned them so that the stack is as it was before
+    fn unlift(&self, stack: &mut Vec<char>, crates: Vec<char>);
+
+    // the number of lifts needed to move count crates
+    fn cost(&self, count: usize) -> usize;
+}
+
+// Moves crates one at a time, reversing their order
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_string()
+    }
+
+    fn lift(&self, stack: &mut Vec<char>, count: usize) -> Vec<char> {
+        let mut crates = stack.split_off(stack.len() - count);
+        crates.reverse();
+        crates
+    }
+
+    fn unlift(&self, stack: &mut Vec<char>, crates: Vec<char>) {
+        stack.extend(crates.into_iter().rev());
+    }
+
+    fn cost(&self, count: usize) -> usize {
+        count
+    }
+}
+
+// Moves crates all at once, keeping their order
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_string()
+    }
+
+    fn lift(&self, stack: &mut Vec<char>, count: usize) -> Vec<char> {
+        stack.split_off(stack.len() - count)
+    }
+
+    fn unlift(&self, stack: &mut Vec<char>, crates: Vec<char>) {
+        stack.extend(crates);
+    }
+
+    fn cost(&self, count: usize) -> usize {
+        count.min(1)
+    }
+}
+
+// Moves up to capacity crates at a time, keeping the order of each lift
+pub struct Limited {
+    pub capacity: usize
+}
+
+impl Crane for Limited {
+    fn name(&self) -> String {
+        format!("CrateMover limited to {}", self.capacity)
+    }
+
+    fn lift(&self, stack: &mut Vec<char>, count: usize) -> Vec<char> {
+        let mut crates = Vec::new();
+        let mut remaining = count;
+        while remaining > 0 {
+            let lift = remaining.min(self.capacity);
+            crates.extend(stack.split_off(stack.len() - lift));
+            remaining -= lift;
+        }
+        crates
+    }
+
+    // each lift was of capacity crates except the last, and took the crates below the one before
+    fn unlift(&self, stack: &mut Vec<char>, crates: Vec<char>) {
+        stack.extend(crates.chunks(self.capacity).rev().flatten());
+    }
+
+    fn cost(&self, count: usize) -> usize {
+        count.div_ceil(self.capacity)
+    }
+}
+
+// Slides crates out from the bottom of the stack all at once, keeping their order
+pub struct FromBottom;
+
+impl Crane for FromBottom {
+    fn name(&self) -> String {
+        "CrateMover from the bottom".to_string()
+    }
+
+    fn lift(&self, stack: &mut Vec<char>, count: usize) -> Vec<char> {
+        stack.drain(0..count).collect()
+    }
+
+    fn unlift(&self, stack: &mut Vec<char>, crates: Vec<char>) {
+        stack.splice(0..0, crates);
+    }
+
+    fn cost(&self, count: usize) -> usize {
+        count.min(1)
+    }
+}
+
+// eg. "9000", "9001", "limited:3" or "bottom"
+pub fn parse_crane(text: &str) -> Result<Box<dyn Crane>, String> {
+    match text {
+        "9000" => Ok(Box::new(CrateMover9000)),
+        "9001" => Ok(Box::new(CrateMover9001)),
+        "bottom" => Ok(Box::new(FromBottom)),
+        _ => match text.strip_prefix("limited:").map(|n| n.parse::<usize>()) {
+            Some(Ok(capacity)) if capacity > 0 => Ok(Box::new(Limited { capacity })),
+            _ => Err(format!("expected a crane of 9000, 9001, limited:N or bottom but found \"{}\"", text))
+        }
+    }
+}
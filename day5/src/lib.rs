@@ -0,0 +1,302 @@
+mod crane;
+
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{lines, numbered_blocks, parse_lines, parse_number};
+use std::str::FromStr;
+pub use crane::{parse_crane, Crane, CrateMover9000, CrateMover9001, FromBottom, Limited};
+
+#[derive(Debug)]
+pub struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize
+}
+
+#[derive(Default)]
+pub struct Day5 {
+    // how many moves to show the stacks after in --details, or all of them if None
+    pub step: Option<usize>,
+    // the crane used for both parts, in place of the CrateMover 9000 for part 1 and 9001 for part 2
+    pub crane: Option<Box<dyn Crane>>
+}
+
+impl Solver for Day5 {
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        let sections: Vec<(usize, &str)> = numbered_blocks(text).collect();
+        if sections.len() != 2 {
+            return Err(ParseError::new(1, format!("expected 2 sections (stacks then instructions) but found {}", sections.len())));
+        }
+        let original_stacks = parse_starting_map(sections[0].1)?;
+        let (offset, instruction_text) = sections[1];
+        let instructions: Vec<Instruction> = parse_lines(instruction_text).map_err(|e| e.offset(offset))?;
+        // every crane moves the same number of crates, so any of them finds the impossible moves
+        let mut simulator = Simulator::new(original_stacks.clone(), &CrateMover9000);
+        for (i, instruction) in instructions.iter().enumerate() {
+            simulator.step(instruction).map_err(|e| ParseError::new(offset + i + 1, e))?;
+        }
+        Ok((original_stacks, instructions))
+    }
+
+    fn part1(&self, (original_stacks, instructions): &Self::Input) -> String {
+        let mut simulator = Simulator::new(original_stacks.clone(), self.cranes()[0]);
+        simulator.run(instructions).expect("validated by parse");
+        simulator.tops()
+    }
+
+    fn part2(&self, (original_stacks, instructions): &Self::Input) -> String {
+        let mut simulator = Simulator::new(original_stacks.clone(), self.cranes()[1]);
+        simulator.run(instructions).expect("validated by parse");
+        simulator.tops()
+    }
+
+    fn details(&self, (original_stacks, instructions): &Self::Input) -> Option<String> {
+        let step = self.step.unwrap_or(instructions.len()).min(instructions.len());
+        let mut sections = Vec::new();
+        // both parts use the same crane when one is given
+        let cranes = if self.crane.is_some() { 1 } else { 2 };
+        for crane in self.cranes().into_iter().take(cranes) {
+            let mut simulator = Simulator::new(original_stacks.clone(), crane);
+            simulator.seek(instructions, step).expect("validated by parse");
+            sections.push(format!("The {} after {} of {} moves ({} lifts):\n{}", crane.name(), step, instructions.len(), simulator.cost(), simulator.diagram()));
+        }
+        Some(sections.join("\n\n"))
+    }
+}
+
+impl Day5 {
+    fn cranes(&self) -> [&dyn Crane; 2] {
+        match &self.crane {
+            Some(crane) => [crane.as_ref(), crane.as_ref()],
+            None => [&CrateMover9000, &CrateMover9001]
+        }
+    }
+}
+
+// A move which has been made, which can be undone by lifting the crates it placed back off the top of the stack they went to
+struct Move {
+    from: usize,
+    to: usize,
+    placed: usize,
+    cost: usize
+}
+
+// Runs instructions against the stacks one at a time, keeping every move so far to step backwards through
+pub struct Simulator<'a> {
+    stacks: Vec<Vec<char>>,
+    crane: &'a dyn Crane,
+    history: Vec<Move>
+}
+
+impl<'a> Simulator<'a> {
+    pub fn new(stacks: Vec<Vec<char>>, crane: &'a dyn Crane) -> Self {
+        Simulator { stacks, crane, history: Vec::new() }
+    }
+
+    pub fn step(&mut self, instruction: &Instruction) -> Result<(), String> {
+        for stack in [instruction.from, instruction.to] {
+            if stack == 0 || stack > self.stacks.len() {
+                return Err(format!("stack {} does not exist", stack));
+            }
+        }
+        let from = &mut self.stacks[instruction.from - 1];
+        if instruction.count > from.len() {
+            return Err(format!("cannot move {} crates from stack {} which only has {}", instruction.count, instruction.from, from.len()));
+        }
+        let moved = self.crane.lift(from, instruction.count);
+        let placed = moved.len();
+        self.stacks[instruction.to - 1].extend(moved);
+        self.history.push(Move { from: instruction.from - 1, to: instruction.to - 1, placed, cost: self.crane.cost(instruction.count) });
+        Ok(())
+    }
+
+    // returns false if there was no move to undo
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(m) => {
+                let to = &mut self.stacks[m.to];
+                let crates = to.split_off(to.len() - m.placed);
+                self.crane.unlift(&mut self.stacks[m.from], crates);
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn run(&mut self, instructions: &[Instruction]) -> Result<(), String> {
+        for instruction in instructions {
+            self.step(instruction)?;
+        }
+        Ok(())
+    }
+
+    // steps forwards or backwards through the instructions until the given number of them have been made
+    pub fn seek(&mut self, instructions: &[Instruction], step: usize) -> Result<(), String> {
+        while self.history.len() > step {
+            self.undo();
+        }
+        while self.history.len() < step.min(instructions.len()) {
+            self.step(&instructions[self.history.len()])?;
+        }
+        Ok(())
+    }
+
+    pub fn steps(&self) -> usize {
+        self.history.len()
+    }
+
+    // the lifts needed for every move so far
+    pub fn cost(&self) -> usize {
+        self.history.iter().map(|m| m.cost).sum()
+    }
+
+    pub fn stacks(&self) -> &[Vec<char>] {
+        &self.stacks
+    }
+
+    // the crate on top of each stack, or a space for an empty stack
+    pub fn tops(&self) -> String {
+        self.stacks.iter().map(|s| s.last().copied().unwrap_or(' ')).collect()
+    }
+
+    // drawn the same way as the puzzle input, so that it can be read back by parse_starting_map
+    pub fn diagram(&self) -> String {
+        let height = self.stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+        let mut lines = Vec::new();
+        for level in (0..height).rev() {
+            let cells: Vec<String> = self.stacks.iter().map(|s| match s.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string()
+            }).collect();
+            lines.push(cells.join(" "));
+        }
+        let labels: Vec<String> = (1..(self.stacks.len()+1)).map(|n| format!(" {} ", n)).collect();
+        lines.push(labels.join(" "));
+        lines.join("\n")
+    }
+}
+
+fn parse_starting_map(text: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut lines: Vec<&str> = lines(text).collect();
+    if lines.is_empty() {
+        return Err(ParseError::new(1, "expected stacks of crates above their numbers".to_string()));
+    }
+    let label_line = lines.len();
+    lines.reverse();
+    if lines[0].trim().split("   ").any(|s| s.trim().parse::<usize>().is_err()) {
+        return Err(ParseError::new(label_line, format!("expected stack numbers but found \"{}\"", lines[0])));
+    }
+    let count: usize = lines[0].split("   ").count();
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    for _ in 0..count {
+        stacks.push(Vec::new());
+    }
+    for line in lines.iter().skip(1) {
+        for (s, stack) in stacks.iter_mut().enumerate() {
+            // trailing spaces may have been trimmed from the line
+            let c: char = line.chars().nth(char_index_for_stack(s)).unwrap_or(' ');
+            if c != ' ' {
+                stack.push(c);
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+fn char_index_for_stack(stack_index: usize) -> usize {
+    1 + 4 * stack_index
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        // move 1 from 2 to 1
+        let words: Vec<&str> = line.split(" ").collect();
+        if words.len() != 6 {
+            return Err(format!("expected 6 words in \"{}\"", line));
+        }
+        Ok(Instruction {
+            count: parse_number(words[1])?,
+            from: parse_number(words[3])?,
+            to: parse_number(words[5])?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day5::default().part1(&input), "CMZ");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day5::default().part2(&input), "MCD");
+    }
+
+    #[test]
+    fn replays_moves() {
+        let (stacks, instructions) = Day5::default().parse(&normalise(EXAMPLE)).unwrap();
+        let mut simulator = Simulator::new(stacks, &CrateMover9000);
+        let diagram = normalise(EXAMPLE).split("\n\n").next().unwrap().to_string();
+        assert_eq!(simulator.diagram(), diagram);
+        simulator.seek(&instructions, 2).unwrap();
+        assert_eq!(simulator.tops(), " CZ");
+        assert_eq!(parse_starting_map(&simulator.diagram()).unwrap(), simulator.stacks());
+        simulator.seek(&instructions, 0).unwrap();
+        assert_eq!(simulator.diagram(), diagram);
+        assert!(!simulator.undo());
+    }
+
+    #[test]
+    fn rejects_impossible_moves() {
+        let error = Day5::default().parse("[A]\n 1 \n\nmove 1 from 1 to 1\nmove 2 from 1 to 1").unwrap_err();
+        assert_eq!(error.to_string(), "5: cannot move 2 crates from stack 1 which only has 1");
+        let error = Day5::default().parse("\n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!(error.to_string(), "1: expected stacks of crates above their numbers");
+    }
+
+    #[test]
+    fn models_cranes() {
+        let (stacks, instructions) = Day5::default().parse(&normalise(EXAMPLE)).unwrap();
+        let tops = |crane: &dyn Crane| {
+            let mut simulator = Simulator::new(stacks.clone(), crane);
+            simulator.run(&instructions).unwrap();
+            (simulator.tops(), simulator.cost())
+        };
+        assert_eq!(tops(&Limited { capacity: 1 }), ("CMZ".to_string(), 7));
+        assert_eq!(tops(&Limited { capacity: 2 }), ("MCZ".to_string(), 5));
+        assert_eq!(tops(&FromBottom), ("DCM".to_string(), 4));
+        assert!(parse_crane("limited:0").is_err());
+    }
+
+    #[test]
+    fn undoes_every_crane() {
+        let stacks = vec![vec!['A', 'B', 'C', 'D', 'E'], vec!['F']];
+        let instructions: Vec<Instruction> = parse_lines("move 4 from 1 to 2\nmove 3 from 2 to 2\nmove 5 from 2 to 1").unwrap();
+        let cranes: [&dyn Crane; 4] = [&CrateMover9000, &CrateMover9001, &Limited { capacity: 3 }, &FromBottom];
+        for crane in cranes {
+            let mut simulator = Simulator::new(stacks.clone(), crane);
+            let mut seen = vec![simulator.stacks().to_vec()];
+            for instruction in &instructions {
+                simulator.step(instruction).unwrap();
+                seen.push(simulator.stacks().to_vec());
+            }
+            while simulator.steps() > 0 {
+                seen.pop();
+                simulator.undo();
+                assert_eq!(simulator.stacks(), seen.last().unwrap(), "{}", crane.name());
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,203 @@
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::skip_byte_order_mark;
+use std::collections::VecDeque;
+use std::io::{self, BufRead};
+
+// marker offsets listed by --details before the rest are just counted
+const MARKERS_SHOWN: usize = 10;
+// the sizes of start-of-packet and start-of-message markers, for parts 1 and 2
+const SIZES: [usize; 2] = [4, 14];
+
+#[derive(Default)]
+pub struct Day6;
+
+impl Solver for Day6 {
+    // what was found in each line, for each of SIZES
+    type Input = Vec<[Scan; 2]>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        Ok(scan(text.as_bytes()).expect("reading from memory"))
+    }
+
+    // each line can be any length, so is scanned as it is read rather than kept
+    fn parse_reader<R: BufRead>(&self, reader: R) -> io::Result<Result<Self::Input, ParseError>> {
+        Ok(Ok(scan(reader)?))
+    }
+
+    fn part1(&self, lines: &Self::Input) -> String {
+        lines.iter().map(|scans| describe(scans[0].first())).collect::<Vec<String>>().join(", ")
+    }
+
+    fn part2(&self, lines: &Self::Input) -> String {
+        lines.iter().map(|scans| describe(scans[1].first())).collect::<Vec<String>>().join(", ")
+    }
+
+    fn details(&self, lines: &Self::Input) -> Option<String> {
+        let mut details = Vec::new();
+        for (i, scans) in lines.iter().enumerate() {
+            for (size, scan) in SIZES.iter().zip(scans) {
+                let shown: Vec<String> = scan.shown.iter().map(|m| m.to_string()).collect();
+                let more = if scan.count > MARKERS_SHOWN { format!(" and {} more", scan.count - MARKERS_SHOWN) } else { String::new() };
+                details.push(format!("Line {}: {} markers of {}: {}{}", i + 1, scan.count, size, shown.join(", "), more));
+            }
+        }
+        Some(details.join("\n"))
+    }
+}
+
+fn describe(marker: Option<usize>) -> String {
+    match marker {
+        Some(offset) => offset.to_string(),
+        None => "none".to_string()
+    }
+}
+
+// The markers of one size found in a line, keeping only the first few however long the line is
+#[derive(Debug, PartialEq, Default)]
+pub struct Scan {
+    pub shown: Vec<usize>,
+    pub count: usize
+}
+
+impl Scan {
+    pub fn first(&self) -> Option<usize> {
+        self.shown.first().copied()
+    }
+
+    fn found(&mut self, marker: usize) {
+        if self.shown.len() < MARKERS_SHOWN {
+            self.shown.push(marker);
+        }
+        self.count += 1;
+    }
+}
+
+// Tracks the last size bytes of a stream, fed one at a time, and whether they are all different
+pub struct Detector {
+    size: usize,
+    window: VecDeque<u8>,
+    counts: [usize; 256],
+    // the number of different bytes appearing more than once in the window
+    duplicates: usize,
+    position: usize
+}
+
+impl Detector {
+    pub fn new(size: usize) -> Self {
+        Detector { size, window: VecDeque::with_capacity(size + 1), counts: [0; 256], duplicates: 0, position: 0 }
+    }
+
+    // returns the number of bytes read so far if they end with a marker
+    pub fn push(&mut self, byte: u8) -> Option<usize> {
+        self.position += 1;
+        self.window.push_back(byte);
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 2 {
+            self.duplicates += 1;
+        }
+        if self.window.len() > self.size {
+            let oldest = self.window.pop_front().expect("window is not empty") as usize;
+            self.counts[oldest] -= 1;
+            if self.counts[oldest] == 1 {
+                self.duplicates -= 1;
+            }
+        }
+        if self.window.len() == self.size && self.duplicates == 0 {
+            Some(self.position)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn first_marker<I: IntoIterator<Item = u8>>(bytes: I, size: usize) -> Option<usize> {
+    let mut detector = Detector::new(size);
+    bytes.into_iter().find_map(|b| detector.push(b))
+}
+
+pub fn markers<I: IntoIterator<Item = u8>>(bytes: I, size: usize) -> Vec<usize> {
+    let mut detector = Detector::new(size);
+    bytes.into_iter().filter_map(|b| detector.push(b)).collect()
+}
+
+// Reads a stream incrementally (eg. from stdin), finding the markers of SIZES in each line without holding the line in memory.
+// A "\r" is only dropped as part of a "\r\n" line ending, and blank lines at the end are ignored.
+pub fn scan<R: BufRead>(mut reader: R) -> io::Result<Vec<[Scan; 2]>> {
+    skip_byte_order_mark(&mut reader)?;
+    let mut lines = Vec::new();
+    let mut detectors = SIZES.map(Detector::new);
+    let mut scans: [Scan; 2] = Default::default();
+    // blank lines seen since the last line which wasn't, only kept if another line follows
+    let mut blanks = 0;
+    let mut carriage_return = false;
+    for byte in reader.bytes() {
+        let byte = byte?;
+        if carriage_return && byte != b'\n' {
+            push(&mut detectors, &mut scans, b'\r');
+        }
+        carriage_return = byte == b'\r';
+        match byte {
+            b'\n' if detectors[0].position == 0 => blanks += 1,
+            b'\n' => {
+                lines.extend((0..blanks).map(|_| Default::default()));
+                blanks = 0;
+                lines.push(std::mem::take(&mut scans));
+                detectors = SIZES.map(Detector::new);
+            },
+            b'\r' => {},
+            b => push(&mut detectors, &mut scans, b)
+        }
+    }
+    if carriage_return {
+        push(&mut detectors, &mut scans, b'\r');
+    }
+    if detectors[0].position > 0 {
+        lines.extend((0..blanks).map(|_| Default::default()));
+        lines.push(scans);
+    }
+    Ok(lines)
+}
+
+fn push(detectors: &mut [Detector; 2], scans: &mut [Scan; 2], byte: u8) {
+    for (detector, scan) in detectors.iter_mut().zip(scans.iter_mut()) {
+        if let Some(marker) = detector.push(byte) {
+            scan.found(marker);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day6.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day6.part1(&input), "7, 5, 6, 10, 11");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6.parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day6.part2(&input), "19, 23, 23, 29, 26");
+    }
+
+    #[test]
+    fn finds_every_marker() {
+        assert_eq!(markers("abcabcc".bytes(), 3), vec![3, 4, 5, 6]);
+        assert_eq!(first_marker("aaaa".bytes(), 2), None);
+    }
+
+    #[test]
+    fn scans_unnormalised_lines() {
+        let lines = Day6.parse_reader("\u{feff}abcd\r\n\r\nab\rcd\r\n\n".as_bytes()).unwrap().unwrap();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0][0], Scan { shown: vec![4], count: 1 });
+        assert_eq!(lines[1][0], Scan::default());
+        // a lone carriage return is part of the line
+        assert_eq!(lines[2][0], Scan { shown: vec![4, 5], count: 2 });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use crate::filesystem::{Filesystem, ROOT};
+
+// The smallest total of non-nested files and directories which frees at least needed, or None if everything together is
+// not enough. Deleting a directory frees exactly the files below it, so this is the subset of files with the smallest
+// total of at least needed, with any directory whose files are all chosen given instead of them.
+pub fn plan(fs: &Filesystem, needed: usize) -> Option<Vec<usize>> {
+    if needed == 0 {
+        return Some(Vec::new());
+    } else if needed > fs.size(ROOT) {
+        return None;
+    }
+    let files: Vec<usize> = fs.walk(ROOT).into_iter().map(|(id, _)| id).filter(|id| !fs.is_dir(*id) && fs.size(*id) > 0).collect();
+    let sizes: Vec<usize> = files.iter().map(|f| fs.size(*f)).collect();
+    let largest = *sizes.iter().max()?;
+    // the best total is less than needed + largest, since it would still be enough without any one of its files
+    let limit = needed + largest;
+    let reachable = reachable(&sizes, limit - 1);
+    let total = (needed..limit).find(|t| contains(&reachable, *t))?;
+    drop(reachable);
+    let mut chosen = vec![false; fs.len()];
+    for f in subset(&sizes, total) {
+        chosen[files[f]] = true;
+    }
+    // a directory is deleted whole when every file in it is chosen, counting up from the files
+    let walk = fs.walk(ROOT);
+    let mut remaining = vec![0; fs.len()];
+    for (id, _) in walk.iter().rev() {
+        if !fs.is_dir(*id) && fs.size(*id) > 0 && !chosen[*id] {
+            remaining[*id] = 1;
+        }
+        let below: usize = fs.children(*id).iter().map(|c| remaining[*c]).sum();
+        remaining[*id] += below;
+        chosen[*id] = remaining[*id] == 0 && (chosen[*id] || fs.children(*id).iter().any(|c| chosen[*c]));
+    }
+    Some(walk.into_iter().map(|(id, _)| id).filter(|id| chosen[*id] && fs.parent(*id).is_none_or(|p| !chosen[p])).collect())
+}
+
+// The totals up to max which some subset of sizes adds up to, as a bitset
+fn reachable(sizes: &[usize], max: usize) -> Vec<u64> {
+    let mut reachable = vec![0u64; max / 64 + 1];
+    reachable[0] = 1;
+    for size in sizes {
+        let (words, bits) = (size / 64, size % 64);
+        // from the top down, so that each word is shifted before anything below it changes
+        for w in (words..reachable.len()).rev() {
+            let mut shifted = reachable[w - words] << bits;
+            if bits > 0 && w > words {
+                shifted |= reachable[w - words - 1] >> (64 - bits);
+            }
+            reachable[w] |= shifted;
+        }
+    }
+    reachable
+}
+
+fn contains(bitset: &[u64], total: usize) -> bool {
+    bitset[total / 64] & (1 << (total % 64)) != 0
+}
+
+// The indices of some of sizes adding up to total, which must be reachable. Rather than remembering how every total was
+// reached, each half of the sizes is searched for a total which the other half makes up the rest of, so that only a
+// couple of bitsets are ever kept at once.
+fn subset(sizes: &[usize], total: usize) -> Vec<usize> {
+    match sizes {
+        _ if total == 0 => Vec::new(),
+        [size] => {
+            debug_assert_eq!(*size, total);
+            vec![0]
+        },
+        _ => {
+            let middle = sizes.len() / 2;
+            let (left, right) = sizes.split_at(middle);
+            let (reachable_left, reachable_right) = (reachable(left, total), reachable(right, total));
+            let from_left = (0..=total).find(|t| contains(&reachable_left, *t) && contains(&reachable_right, total - t))
+                .expect("total is reachable");
+            drop((reachable_left, reachable_right));
+            let mut chosen = subset(left, from_left);
+            chosen.extend(subset(right, total - from_left).into_iter().map(|i| i + middle));
+            chosen
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use aoc_common::input::parse_number;
+use std::str::FromStr;
+
+pub const ROOT: usize = 0;
+
+pub enum Kind {
+    // the children are kept in the order they were found, and the size is the total of everything below
+    Dir { children: Vec<usize>, size: usize },
+    File { size: usize }
+}
+
+pub struct Node {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub kind: Kind
+}
+
+// Every file and directory seen in a transcript, by index from the root at 0
+pub struct Filesystem {
+    nodes: Vec<Node>
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Filesystem::new()
+    }
+}
+
+impl Filesystem {
+    pub fn new() -> Self {
+        Filesystem { nodes: vec![Node { name: "/".to_string(), parent: None, kind: Kind::Dir { children: Vec::new(), size: 0 } }] }
+    }
+
+    // never empty, since the root is always there
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn node(&self, id: usize) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn is_dir(&self, id: usize) -> bool {
+        matches!(self.nodes[id].kind, Kind::Dir { .. })
+    }
+
+    pub fn size(&self, id: usize) -> usize {
+        match self.nodes[id].kind {
+            Kind::Dir { size, .. } | Kind::File { size } => size
+        }
+    }
+
+    pub fn children(&self, id: usize) -> &[usize] {
+        match &self.nodes[id].kind {
+            Kind::Dir { children, .. } => children,
+            Kind::File { .. } => &[]
+        }
+    }
+
+    pub fn child(&self, dir: usize, name: &str) -> Option<usize> {
+        self.children(dir).iter().copied().find(|c| self.nodes[*c].name == name)
+    }
+
+    pub fn parent(&self, id: usize) -> Option<usize> {
+        self.nodes[id].parent
+    }
+
+    // the directory of this name within dir, which is created if it has not been seen yet
+    pub fn add_dir(&mut self, dir: usize, name: &str) -> usize {
+        if let Some(existing) = self.child(dir, name) {
+            return existing;
+        }
+        self.push(dir, name, Kind::Dir { children: Vec::new(), size: 0 })
+    }
+
+    pub fn add_file(&mut self, dir: usize, name: &str, size: usize) -> usize {
+        let id = self.push(dir, name, Kind::File { size });
+        self.resize_dirs(dir, 0, size);
+        id
+    }
+
+    // for a file listed again with a different size
+    pub fn resize_file(&mut self, id: usize, new_size: usize) {
+        if let Kind::File { size } = &mut self.nodes[id].kind {
+            let old_size = *size;
+            *size = new_size;
+            let dir = self.nodes[id].parent.expect("files are always in a directory");
+            self.resize_dirs(dir, old_size, new_size);
+        }
+    }
+
+    // keeps the cached size of every directory above a file up to date
+    fn resize_dirs(&mut self, dir: usize, old_size: usize, new_size: usize) {
+        let mut above = Some(dir);
+        while let Some(d) = above {
+            if let Kind::Dir { size: total, .. } = &mut self.nodes[d].kind {
+                *total = *total - old_size + new_size;
+            }
+            above = self.nodes[d].parent;
+        }
+    }
+
+    fn push(&mut self, dir: usize, name: &str, kind: Kind) -> usize {
+        let id = self.nodes.len();
+        self.nodes.push(Node { name: name.to_string(), parent: Some(dir), kind });
+        if let Kind::Dir { children, .. } = &mut self.nodes[dir].kind {
+            children.push(id);
+        }
+        id
+    }
+
+    pub fn path(&self, id: usize) -> String {
+        let mut names = Vec::new();
+        let mut current = id;
+        while let Some(parent) = self.nodes[current].parent {
+            names.push(self.nodes[current].name.as_str());
+            current = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    // an absolute path like "/a/e", or None if nothing is there
+    pub fn lookup(&self, path: &str) -> Option<usize> {
+        path.split('/').filter(|n| !n.is_empty()).try_fold(ROOT, |dir, name| self.child(dir, name))
+    }
+
+    // every node below (and including) id, each parent before its children, with its depth below id
+    pub fn walk(&self, id: usize) -> Vec<(usize, usize)> {
+        let mut nodes = Vec::new();
+        let mut stack = vec![(id, 0)];
+        while let Some((current, depth)) = stack.pop() {
+            nodes.push((current, depth));
+            stack.extend(self.children(current).iter().rev().map(|c| (*c, depth + 1)));
+        }
+        nodes
+    }
+
+    fn dirs_after_contents(&self, id: usize, depth: usize, dirs: &mut Vec<(usize, usize)>) {
+        if self.is_dir(id) {
+            for child in self.children(id) {
+                self.dirs_after_contents(*child, depth + 1, dirs);
+            }
+            dirs.push((id, depth));
+        }
+    }
+
+    pub fn dirs(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.nodes.len()).filter(|id| self.is_dir(*id))
+    }
+
+    pub fn run(&self, query: &Query) -> Result<String, String> {
+        let start = self.lookup(query.path()).ok_or(format!("{}: no such file or directory", query.path()))?;
+        let lines: Vec<String> = match query {
+            Query::Tree { .. } => self.walk(start).into_iter().map(|(id, depth)| {
+                let node = &self.nodes[id];
+                let description = match node.kind {
+                    Kind::Dir { .. } => "dir".to_string(),
+                    Kind::File { size } => format!("file, size={}", size)
+                };
+                format!("{}- {} ({})", "  ".repeat(depth), node.name, description)
+            }).collect(),
+            Query::Du { human, max_depth, .. } => {
+                // like du, each directory comes after everything in it
+                let mut dirs = Vec::new();
+                self.dirs_after_contents(start, 0, &mut dirs);
+                dirs.into_iter().filter(|(_, depth)| max_depth.is_none_or(|max| *depth <= max)).map(|(id, _)| {
+                    let size = if *human { human_size(self.size(id)) } else { self.size(id).to_string() };
+                    format!("{}\t{}", size, self.path(id))
+                }).collect()
+            },
+            Query::Find { min_size, max_size, name, kind, .. } => self.walk(start).into_iter().map(|(id, _)| id).filter(|id| {
+                let size = self.size(*id);
+                min_size.is_none_or(|min| size >= min)
+                    && max_size.is_none_or(|max| size <= max)
+                    && name.as_ref().is_none_or(|pattern| glob(pattern, &self.nodes[*id].name))
+                    && kind.is_none_or(|dir| dir == self.is_dir(*id))
+            }).map(|id| self.path(id)).collect()
+        };
+        Ok(lines.join("\n"))
+    }
+}
+
+// sizes in powers of 1024, eg. 14.8M or 584 (bytes)
+fn human_size(size: usize) -> String {
+    let mut value = size as f64;
+    for unit in ["", "K", "M", "G", "T"] {
+        if value < 1024.0 || unit == "T" {
+            return if unit.is_empty() {
+                format!("{}", size)
+            } else if value < 9.95 {
+                // one decimal place, as long as that would not round up to 10.0
+                format!("{:.1}{}", value, unit)
+            } else {
+                format!("{:.0}{}", value, unit)
+            };
+        }
+        value /= 1024.0;
+    }
+    unreachable!()
+}
+
+// matches * against any number of characters and ? against any one
+fn glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // matches[j] is true when the pattern so far matches the first j characters of name
+    let mut matches = vec![false; name.len() + 1];
+    matches[0] = true;
+    for p in pattern {
+        let mut next = vec![false; name.len() + 1];
+        for j in 0..(name.len()+1) {
+            next[j] = match p {
+                '*' => matches[j] || (j > 0 && next[j - 1]),
+                '?' => j > 0 && matches[j - 1],
+                c => j > 0 && matches[j - 1] && name[j - 1] == c
+            };
+        }
+        matches = next;
+    }
+    matches[name.len()]
+}
+
+// A question to ask about a filesystem, written like the shell command it imitates:
+//   tree [path]
+//   du [-h] [--max-depth N] [path]
+//   find [path] [--min-size N] [--max-size N] [--name pattern] [--type f|d]
+pub enum Query {
+    Tree { path: String },
+    Du { path: String, human: bool, max_depth: Option<usize> },
+    Find { path: String, min_size: Option<usize>, max_size: Option<usize>, name: Option<String>, kind: Option<bool> }
+}
+
+impl Query {
+    fn path(&self) -> &str {
+        match self {
+            Query::Tree { path } | Query::Du { path, .. } | Query::Find { path, .. } => path
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut words = text.split_whitespace();
+        let command = words.next().ok_or("expected tree, du or find but found nothing".to_string())?;
+        let mut path = None;
+        let mut human = false;
+        let mut max_depth = None;
+        let mut min_size = None;
+        let mut max_size = None;
+        let mut name = None;
+        let mut kind = None;
+        while let Some(word) = words.next() {
+            let mut value = || words.next().ok_or(format!("expected a value after {}", word));
+            match (command, word) {
+                ("du", "-h") => human = true,
+                ("du", "--max-depth") => max_depth = Some(parse_number(value()?)?),
+                ("find", "--min-size") => min_size = Some(parse_number(value()?)?),
+                ("find", "--max-size") => max_size = Some(parse_number(value()?)?),
+                ("find", "--name") => name = Some(value()?.to_string()),
+                ("find", "--type") => kind = Some(match value()? {
+                    "d" => true,
+                    "f" => false,
+                    other => return Err(format!("expected a --type of f or d but found \"{}\"", other))
+                }),
+                (_, option) if option.starts_with('-') => return Err(format!("unknown option \"{}\" for {}", option, command)),
+                (_, p) if path.is_none() => path = Some(p.to_string()),
+                (_, other) => return Err(format!("unexpected \"{}\" in \"{}\"", other, text))
+            }
+        }
+        let path = path.unwrap_or("/".to_string());
+        match command {
+            "tree" => Ok(Query::Tree { path }),
+            "du" => Ok(Query::Du { path, human, max_depth }),
+            "find" => Ok(Query::Find { path, min_size, max_size, name, kind }),
+            _ => Err(format!("expected tree, du or find but found \"{}\"", command))
+        }
+    }
+}
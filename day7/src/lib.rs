@@ -0,0 +1,272 @@
+mod cleanup;
+mod filesystem;
+
+use aoc_common::{ParseError, Solver};
+use aoc_common::input::{lines, parse_number};
+use std::collections::HashMap;
+use std::str::FromStr;
+pub use cleanup::plan;
+pub use filesystem::{Filesystem, Kind, Node, Query, ROOT};
+
+struct File {
+    name: String,
+    size: usize
+}
+
+enum Command {
+    ChangeDir(String),
+    ChangeRoot,
+    ChangeBack,
+    // each listing with its line number
+    List(Vec<(usize, Listing)>)
+}
+
+enum Listing {
+    Dir(String),
+    File(File)
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if let Some(dir) = text.strip_prefix("cd ") {
+            if dir.eq("..") {
+                Ok(Command::ChangeBack)
+            } else if dir.eq("/") {
+                Ok(Command::ChangeRoot)
+            } else {
+                Ok(Command::ChangeDir(dir.to_owned()))
+            }
+        } else if text.eq("ls") {
+            Ok(Command::List(Vec::new()))
+        } else {
+            Err(format!("unknown command \"{}\"", text))
+        }
+    }
+}
+
+impl FromStr for Listing {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = line.split(" ").collect();
+        if words.len() != 2 {
+            return Err(format!("expected 2 words in \"{}\"", line));
+        }
+        if words[0].eq("dir") {
+            Ok(Listing::Dir(words[1].to_owned()))
+        } else {
+            Ok(Listing::File(File { name: words[1].to_owned(), size: parse_number(words[0])? }))
+        }
+    }
+}
+
+// each "$ " line starts a command, and any other lines are the output of the last ls
+fn parse_commands(text: &str) -> Result<Vec<(usize, Command)>, ParseError> {
+    let mut commands = Vec::new();
+    for (i, line) in lines(text).enumerate() {
+        if let Some(command) = line.strip_prefix("$ ") {
+            commands.push((i + 1, command.parse().map_err(|e| ParseError::new(i + 1, e))?));
+        } else if let Some((_, Command::List(list))) = commands.last_mut() {
+            list.push((i + 1, line.parse().map_err(|e| ParseError::new(i + 1, e))?));
+        } else {
+            return Err(ParseError::new(i + 1, format!("expected a command but found \"{}\"", line)));
+        }
+    }
+    Ok(commands)
+}
+
+// The filesystem a transcript describes, with anything odd about it
+pub struct Transcript {
+    pub fs: Filesystem,
+    pub warnings: Vec<ParseError>
+}
+
+pub struct Day7 {
+    // shown by --details, eg. "du -h --max-depth 1"
+    pub queries: Vec<Query>,
+    pub disk_size: usize,
+    // the free space needed in part 2
+    pub required: usize
+}
+
+impl Default for Day7 {
+    fn default() -> Self {
+        Day7 { queries: Vec::new(), disk_size: 70000000, required: 30000000 }
+    }
+}
+
+impl Day7 {
+    // how much more space needs to be freed
+    pub fn needed(&self, fs: &Filesystem) -> usize {
+        let free = self.disk_size.saturating_sub(fs.size(ROOT));
+        self.required.saturating_sub(free)
+    }
+}
+
+impl Solver for Day7 {
+    type Input = Transcript;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        Ok(build_filesystem(parse_commands(text)?))
+    }
+
+    fn part1(&self, Transcript { fs, .. }: &Self::Input) -> String {
+        let sum: usize = fs.dirs().map(|d| fs.size(d)).filter(|s| *s <= 100000).sum();
+        format!("{}", sum)
+    }
+
+    fn part2(&self, Transcript { fs, .. }: &Self::Input) -> String {
+        let needed = self.needed(fs);
+        if needed == 0 {
+            return "0".to_string();
+        }
+        match fs.dirs().map(|d| fs.size(d)).filter(|s| *s >= needed).min() {
+            Some(size) => format!("{}", size),
+            None => "none".to_string()
+        }
+    }
+
+    fn warnings(&self, transcript: &Self::Input) -> Vec<ParseError> {
+        transcript.warnings.clone()
+    }
+
+    fn details(&self, Transcript { fs, .. }: &Self::Input) -> Option<String> {
+        let default = [Query::Du { path: "/".to_string(), human: true, max_depth: Some(1) }];
+        let queries = if self.queries.is_empty() { &default[..] } else { &self.queries[..] };
+        let mut results: Vec<String> = queries.iter().map(|q| match fs.run(q) {
+            Ok(output) => output,
+            Err(e) => format!("Error: {}", e)
+        }).collect();
+        let needed = self.needed(fs);
+        if needed > 0 {
+            let mut lines = vec![format!("Cleanup to free another {}:", needed)];
+            match plan(fs, needed) {
+                Some(chosen) => {
+                    lines.extend(chosen.iter().map(|id| format!("{}\t{}", fs.size(*id), fs.path(*id))));
+                    lines.push(format!("{}\ttotal", chosen.iter().map(|id| fs.size(*id)).sum::<usize>()));
+                },
+                None => lines.push("impossible, even deleting everything".to_string())
+            }
+            results.push(lines.join("\n"));
+        }
+        Some(results.join("\n\n"))
+    }
+}
+
+// repeated listings are merged, and anything contradictory is kept as a warning rather than stopping
+fn build_filesystem(commands: Vec<(usize, Command)>) -> Transcript {
+    let mut fs = Filesystem::new();
+    let mut warnings = Vec::new();
+    // the line each node was last listed on
+    let mut listed: HashMap<usize, usize> = HashMap::new();
+    let mut pwd = ROOT;
+    for (line, command) in commands {
+        match command {
+            Command::ChangeDir(dir) => match fs.child(pwd, &dir) {
+                Some(existing) if fs.is_dir(existing) => pwd = existing,
+                Some(file) => warnings.push(ParseError::new(line, format!("cannot cd into {} which is a file", fs.path(file)))),
+                None => {
+                    pwd = fs.add_dir(pwd, &dir);
+                    listed.insert(pwd, line);
+                    warnings.push(ParseError::new(line, format!("cd into {} which was never listed", fs.path(pwd))));
+                }
+            },
+            Command::ChangeRoot => pwd = ROOT,
+            Command::ChangeBack => match fs.parent(pwd) {
+                Some(parent) => pwd = parent,
+                None => warnings.push(ParseError::new(line, "cd .. from / stays in /".to_string()))
+            },
+            Command::List(list) => {
+                for (line, listing) in list {
+                    let (name, size) = match &listing {
+                        Listing::Dir(name) => (name, None),
+                        Listing::File(file) => (&file.name, Some(file.size))
+                    };
+                    let id = match (fs.child(pwd, name), size) {
+                        (None, None) => fs.add_dir(pwd, name),
+                        (None, Some(size)) => fs.add_file(pwd, name, size),
+                        (Some(existing), None) if fs.is_dir(existing) => existing,
+                        (Some(existing), Some(size)) if !fs.is_dir(existing) => {
+                            if fs.size(existing) != size {
+                                warnings.push(ParseError::new(line, format!("{} is {} here but was {} on line {}", fs.path(existing), size, fs.size(existing), listed[&existing])));
+                                fs.resize_file(existing, size);
+                            }
+                            existing
+                        },
+                        (Some(existing), _) => {
+                            let (now, before) = if size.is_some() { ("file", "directory") } else { ("directory", "file") };
+                            warnings.push(ParseError::new(line, format!("{} is listed as a {} here but was a {} on line {}", fs.path(existing), now, before, listed[&existing])));
+                            continue;
+                        }
+                    };
+                    listed.insert(id, line);
+                }
+            }
+        }
+    }
+    Transcript { fs, warnings }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day7::default().part1(&input), "95437");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day7::default().part2(&input), "24933642");
+    }
+
+    #[test]
+    fn answers_queries() {
+        let fs = Day7::default().parse(&normalise(EXAMPLE)).unwrap().fs;
+        let e = fs.lookup("/a/e").unwrap();
+        assert_eq!((fs.size(e), fs.path(e)), (584, "/a/e".to_string()));
+        let run = |query: &str| fs.run(&query.parse().unwrap()).unwrap();
+        assert_eq!(run("du --max-depth 1"), "94853\t/a\n24933642\t/d\n48381165\t/");
+        assert_eq!(run("du -h /d"), "24M\t/d");
+        assert_eq!(run("find --max-size 100000 --type d"), "/a\n/a/e");
+        assert_eq!(run("find --name *.???"), "/a/h.lst\n/b.txt\n/c.dat\n/d/d.log\n/d/d.ext");
+        assert_eq!(run("tree /a/e"), "- e (dir)\n  - i (file, size=584)");
+        assert!(fs.run(&"tree /x".parse().unwrap()).is_err());
+        assert!("du --name x".parse::<Query>().is_err());
+    }
+
+    #[test]
+    fn warns_about_contradictions() {
+        let transcript = Day7::default().parse("$ cd /\n$ ls\n10 a\ndir b\n$ ls\n10 a\ndir b\n20 a\n$ cd c\n$ cd ..\n$ cd ..\n$ ls\n5 b\n$ cd a").unwrap();
+        let warnings: Vec<String> = transcript.warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(warnings, vec![
+            "8: /a is 20 here but was 10 on line 6",
+            "9: cd into /c which was never listed",
+            "11: cd .. from / stays in /",
+            "13: /b is listed as a file here but was a directory on line 7",
+            "14: cannot cd into /a which is a file"
+        ]);
+        assert_eq!(transcript.fs.size(ROOT), 20);
+    }
+
+    #[test]
+    fn plans_cleanup() {
+        let fs = Day7::default().parse(&normalise(EXAMPLE)).unwrap().fs;
+        let paths = |needed| plan(&fs, needed).map(|chosen| chosen.iter().map(|id| fs.path(*id)).collect::<Vec<_>>());
+        assert_eq!(paths(8381165), Some(vec!["/c.dat".to_string()]));
+        assert_eq!(paths(94853), Some(vec!["/a".to_string()]));
+        assert_eq!(paths(8100000), Some(vec!["/a/f".to_string(), "/a/h.lst".to_string(), "/d/d.log".to_string()]));
+        assert_eq!(paths(0), Some(Vec::new()));
+        assert_eq!(paths(48381166), None);
+        let day7 = Day7 { disk_size: 50000000, ..Day7::default() };
+        assert_eq!(day7.part2(&day7.parse(&normalise(EXAMPLE)).unwrap()), "48381165");
+    }
+}
\ No newline at end of file
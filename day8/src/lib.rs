@@ -0,0 +1,159 @@
+mod scenic;
+mod sight;
+
+use aoc_common::{Direction, Grid, ParseError, Point, Solver};
+pub use scenic::{heatmap, most_scenic, scenic_scores, viewing_distances};
+pub use sight::{line_of_sight, overlay, parse_slope, Observer, COMPASS};
+
+pub struct Day8 {
+    // the most scenic trees listed by --details
+    pub best: usize,
+    // someone whose view --details shows, looking along the compass directions and both ways along any slopes
+    pub observer: Option<Observer>,
+    pub slopes: Vec<Point>
+}
+
+impl Default for Day8 {
+    fn default() -> Self {
+        Day8 { best: 5, observer: None, slopes: Vec::new() }
+    }
+}
+
+impl Solver for Day8 {
+    type Input = Grid<u32>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(text, |c| c.to_digit(10).ok_or(format!("expected a tree height but found '{}'", c)))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> String {
+        let edges = visible_from_edges(grid);
+        format!("{}", edges.iter().filter(|(_, e)| e.contains(&true)).count())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> String {
+        format!("{}", scenic_scores(grid).iter().map(|(_, s)| *s).max().unwrap_or(0))
+    }
+
+    fn details(&self, grid: &Self::Input) -> Option<String> {
+        let edges = visible_from_edges(grid);
+        let counts: Vec<String> = ["top", "right", "bottom", "left"].iter().enumerate()
+            .map(|(i, name)| format!("{} {}", name, edges.iter().filter(|(_, e)| e[i]).count()))
+            .collect();
+        let mut lines = vec![format!("Visible from the {}", counts.join(", "))];
+        let scores = scenic_scores(grid);
+        lines.push("Most scenic:".to_string());
+        lines.extend(most_scenic(&scores, self.best).iter().map(|(p, s)| format!("{} at ({},{})", s, p.x, p.y)));
+        lines.push("Scenic scores:".to_string());
+        lines.push(heatmap(&scores));
+        if let Some(observer) = &self.observer {
+            let mut steps = COMPASS.to_vec();
+            for slope in &self.slopes {
+                steps.extend([*slope, -*slope].iter().filter(|s| !COMPASS.contains(s)));
+            }
+            match line_of_sight(grid, observer, &steps) {
+                Ok(visible) => {
+                    lines.push(format!("Seen from ({},{}): {} trees", observer.at.x, observer.at.y, visible.iter().filter(|(_, v)| **v).count()));
+                    lines.push(overlay(grid, &visible, observer));
+                },
+                Err(e) => lines.push(format!("Error: {}", e))
+            }
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+// whether each tree can be seen from the top, right, bottom and left edges, in the order of Direction::ALL
+pub fn visible_from_edges(grid: &Grid<u32>) -> Grid<[bool; 4]> {
+    let mut edges = Grid::new(grid.width(), grid.height(), [false; 4]);
+    for (i, edge) in Direction::ALL.iter().enumerate() {
+        let mut visible = Grid::new(grid.width(), grid.height(), false);
+        mark_visible(&mut visible, grid, edge.reverse());
+        for p in grid.points() {
+            edges[p][i] = visible[p];
+        }
+    }
+    edges
+}
+
+// looks along every row or column in the heading direction, starting from the opposite edge
+fn mark_visible(visible: &mut Grid<bool>, grid: &Grid<u32>, heading: Direction) {
+    let edge: Vec<Point> = grid.points().filter(|p| !grid.contains(&(*p - heading.delta()))).collect();
+    for start in edge {
+        let mut previous = -1;
+        let mut p = start;
+        while let Some(height) = grid.get(&p) {
+            let value = *height as isize;
+            if value > previous {
+                visible[p] = true;
+                previous = value;
+            }
+            p += heading.delta();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day8::default().part1(&input), "21");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day8::default().part2(&input), "8");
+    }
+
+    // walks out from each tree, as the scores used to be found
+    fn slow_scenic_score(grid: &Grid<u32>, from: Point) -> usize {
+        Direction::ALL.iter().map(|heading| {
+            let mut distance = 0;
+            let mut p = from + heading.delta();
+            while let Some(other) = grid.get(&p) {
+                distance += 1;
+                if *other >= grid[from] {
+                    break;
+                }
+                p += heading.delta();
+            }
+            distance
+        }).product()
+    }
+
+    #[test]
+    fn scores_rectangular_grids() {
+        for text in [EXAMPLE, "3037325\n2551267\n6533282", "1\n5\n2\n5\n9", "9"] {
+            let grid = Day8::default().parse(&normalise(text)).unwrap();
+            let scores = scenic_scores(&grid);
+            assert!(grid.points().all(|p| scores[p] == slow_scenic_score(&grid, p)));
+        }
+        let grid = Day8::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(viewing_distances(&grid, Direction::Up).row(3), &[1, 1, 2, 3, 3]);
+        assert_eq!(most_scenic(&scenic_scores(&grid), 3), vec![(Point::new(2, 3), 8), (Point::new(1, 2), 6), (Point::new(2, 1), 4)]);
+        let edges = visible_from_edges(&grid);
+        assert_eq!(edges[Point::new(1, 1)], [true, false, false, true]);
+        assert_eq!(heatmap(&scenic_scores(&grid)).lines().nth(3), Some(" -@* "));
+    }
+
+    #[test]
+    fn looks_from_observers() {
+        let grid = Day8::default().parse(&normalise(EXAMPLE)).unwrap();
+        let observer: Observer = "2,3".parse().unwrap();
+        let visible = line_of_sight(&grid, &observer, &COMPASS).unwrap();
+        assert_eq!(overlay(&grid, &visible, &observer), ".....\n..5.2\n.533.\n33@49\n.539.");
+        let tower: Observer = "0,0,9".parse().unwrap();
+        let visible = line_of_sight(&grid, &tower, &[parse_slope("4,2").unwrap()]).unwrap();
+        assert_eq!(overlay(&grid, &visible, &tower), "@....\n..5..\n....2\n.....\n.....");
+        assert_eq!(parse_slope("-3,6"), Ok(Point::new(-1, 2)));
+        assert!(parse_slope("0,0").is_err() && "1,2,3,4".parse::<Observer>().is_err());
+        assert!(line_of_sight(&grid, &"5,0".parse().unwrap(), &COMPASS).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use aoc_common::{Direction, Grid, Point};
+use std::cmp::Reverse;
+
+// how many trees each tree can see looking in the heading direction, up to and including the first at least as tall
+pub fn viewing_distances(grid: &Grid<u32>, heading: Direction) -> Grid<usize> {
+    let mut distances = Grid::new(grid.width(), grid.height(), 0);
+    // each line is followed back from the edge it looks towards, keeping a stack of the trees ahead which are taller
+    // than everything between them and the current tree, so every tree is pushed and popped at most once
+    let edge: Vec<Point> = grid.points().filter(|p| !grid.contains(&(*p + heading.delta()))).collect();
+    let mut ahead: Vec<(usize, u32)> = Vec::new();
+    for start in edge {
+        ahead.clear();
+        let mut p = start;
+        let mut steps = 0;
+        while let Some(height) = grid.get(&p) {
+            while ahead.last().is_some_and(|(_, h)| h < height) {
+                ahead.pop();
+            }
+            distances[p] = match ahead.last() {
+                Some((blocked, _)) => steps - blocked,
+                None => steps
+            };
+            ahead.push((steps, *height));
+            p -= heading.delta();
+            steps += 1;
+        }
+    }
+    distances
+}
+
+pub fn scenic_scores(grid: &Grid<u32>) -> Grid<usize> {
+    let mut scores = Grid::new(grid.width(), grid.height(), 1);
+    for heading in Direction::ALL {
+        let distances = viewing_distances(grid, heading);
+        for p in grid.points() {
+            scores[p] *= distances[p];
+        }
+    }
+    scores
+}
+
+// the n highest scores, ties in reading order
+pub fn most_scenic(scores: &Grid<usize>, n: usize) -> Vec<(Point, usize)> {
+    let mut trees: Vec<(Point, usize)> = scores.iter().map(|(p, s)| (p, *s)).collect();
+    // a stable sort keeps the reading order of equal scores
+    trees.sort_by_key(|(_, s)| Reverse(*s));
+    trees.truncate(n);
+    trees
+}
+
+// one character per tree from ' ' for a score of 0 up to '@' for the highest, on a log scale since a few trees score
+// far higher than the rest
+pub fn heatmap(scores: &Grid<usize>) -> String {
+    const SHADES: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+    let highest = scores.iter().map(|(_, s)| *s).max().unwrap_or(0);
+    let scale = ((highest + 1) as f64).ln();
+    scores.render(|s| {
+        if *s == 0 {
+            SHADES[0]
+        } else {
+            let shade = ((*s + 1) as f64).ln() / scale * (SHADES.len() - 1) as f64;
+            SHADES[(shade.ceil() as usize).clamp(1, SHADES.len() - 1)]
+        }
+    })
+}
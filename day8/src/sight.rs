@@ -0,0 +1,82 @@
+use aoc_common::input::parse_number;
+use aoc_common::{Grid, Point};
+use std::str::FromStr;
+
+// clockwise from north, with y growing downwards
+pub const COMPASS: [Point; 8] = [
+    Point { x: 0, y: -1 },
+    Point { x: 1, y: -1 },
+    Point { x: 1, y: 0 },
+    Point { x: 1, y: 1 },
+    Point { x: 0, y: 1 },
+    Point { x: -1, y: 1 },
+    Point { x: -1, y: 0 },
+    Point { x: -1, y: -1 }
+];
+
+// Someone standing in the forest, with their eyes at the given height or otherwise at the height of the tree there
+pub struct Observer {
+    pub at: Point,
+    pub height: Option<u32>
+}
+
+// eg. "3,2" or "3,2,7"
+impl FromStr for Observer {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<&str> = text.split(',').collect();
+        match numbers[..] {
+            [x, y] => Ok(Observer { at: Point::new(parse_number(x)?, parse_number(y)?), height: None }),
+            [x, y, height] => Ok(Observer { at: Point::new(parse_number(x)?, parse_number(y)?), height: Some(parse_number(height)?) }),
+            _ => Err(format!("expected X,Y or X,Y,Height but found \"{}\"", text))
+        }
+    }
+}
+
+// a line through the grid as the smallest step along it, eg. "2,1" or "4,2" are both two across for each one down
+pub fn parse_slope(text: &str) -> Result<Point, String> {
+    let (x, y) = text.split_once(',').ok_or(format!("expected DX,DY but found \"{}\"", text))?;
+    let (x, y): (isize, isize) = (parse_number(x)?, parse_number(y)?);
+    if x == 0 && y == 0 {
+        return Err("a slope needs a step other than 0,0".to_string());
+    }
+    let divisor = gcd(x.unsigned_abs(), y.unsigned_abs()) as isize;
+    Ok(Point::new(x / divisor, y / divisor))
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// the trees the observer can see looking each way along the given steps, up to and including the first at least as
+// tall as their eyes; only the trees exactly on each line count, so a step of 2,1 passes between the trees at 1,0 and 1,1
+pub fn line_of_sight(grid: &Grid<u32>, observer: &Observer, steps: &[Point]) -> Result<Grid<bool>, String> {
+    let from = observer.at;
+    let height = observer.height.or(grid.get(&from).copied()).ok_or(format!("({},{}) is outside the forest", from.x, from.y))?;
+    let mut visible = Grid::new(grid.width(), grid.height(), false);
+    for step in steps {
+        let mut p = from + *step;
+        while let Some(other) = grid.get(&p) {
+            visible[p] = true;
+            if *other >= height {
+                break;
+            }
+            p += *step;
+        }
+    }
+    Ok(visible)
+}
+
+// the heights of the visible trees, with '.' for the rest and '@' for the observer if they are in the forest
+pub fn overlay(grid: &Grid<u32>, visible: &Grid<bool>, observer: &Observer) -> String {
+    let mut cells = grid.map(|h| char::from_digit(*h, 10).unwrap_or('?'));
+    for p in grid.points() {
+        if p == observer.at {
+            cells[p] = '@';
+        } else if !visible[p] {
+            cells[p] = '.';
+        }
+    }
+    cells.render(|c| *c)
+}
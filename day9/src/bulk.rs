@@ -0,0 +1,162 @@
+use crate::rope::Follow;
+use aoc_common::{Interval, IntervalSet, Point};
+use std::collections::{BTreeMap, BTreeSet};
+
+// The directions a rope can be swept along in bulk, each of which a Trail keeps runs along
+const DIRECTIONS: [Point; 4] = [Point { x: 1, y: 0 }, Point { x: 0, y: 1 }, Point { x: 1, y: 1 }, Point { x: 1, y: -1 }];
+
+// Runs of positions along parallel lines in one direction, each keyed by the line it is on
+struct Lines {
+    direction: Point,
+    runs: BTreeMap<isize, IntervalSet>
+}
+
+impl Lines {
+    // the same for every point on a line, and different for every line
+    fn key(&self, p: Point) -> isize {
+        p.x * self.direction.y - p.y * self.direction.x
+    }
+
+    // how far along its line a point is, which is x except for columns
+    fn position(&self, p: Point) -> isize {
+        if self.direction.x == 0 { p.y } else { p.x }
+    }
+
+    fn point(&self, key: isize, position: isize) -> Point {
+        if self.direction.x == 0 {
+            Point::new(key, position)
+        } else {
+            Point::new(position, position * self.direction.y - key)
+        }
+    }
+
+    fn contains(&self, p: &Point) -> bool {
+        self.runs.get(&self.key(*p)).is_some_and(|r| r.contains_point(self.position(*p)))
+    }
+
+    // the positions along a run of other lines (in another direction) which are also in one of these runs
+    fn crossings<'b>(&'b self, other: &'b Lines, key: isize, run: &'b Interval) -> impl Iterator<Item = isize> + 'b {
+        let (first, last) = (self.key(other.point(key, run.start)), self.key(other.point(key, run.end)));
+        // each step along the run crosses this many of these lines, which is never 0 as they aren't parallel
+        let slope = self.key(other.point(key, run.start + 1)) - first;
+        self.runs.range(first.min(last)..=first.max(last)).filter_map(move |(k, runs)| {
+            let offset = k - first;
+            if offset % slope != 0 {
+                // a diagonal can pass between the cells of a line which crosses it
+                return None;
+            }
+            let position = run.start + offset / slope;
+            runs.contains_point(self.position(other.point(key, position))).then_some(position)
+        })
+    }
+}
+
+// The positions a knot has been, as runs along rows (for single positions too), columns and both diagonals
+pub struct Trail {
+    lines: [Lines; 4]
+}
+
+impl Default for Trail {
+    fn default() -> Self {
+        Trail { lines: DIRECTIONS.map(|direction| Lines { direction, runs: BTreeMap::new() }) }
+    }
+}
+
+impl Trail {
+    pub fn visit(&mut self, p: Point) {
+        self.sweep(p, p);
+    }
+
+    // every position along a row, column or diagonal from one point to the other
+    pub fn sweep(&mut self, from: Point, to: Point) {
+        let direction = (to - from).signum();
+        if to - from != direction * from.chebyshev(&to) {
+            panic!("({},{}) to ({},{}) is not along a row, column or diagonal", from.x, from.y, to.x, to.y);
+        }
+        // a single position is kept as part of its row
+        let lines = self.lines.iter_mut().find(|l| direction == Point::ORIGIN || l.direction == direction || l.direction == -direction)
+            .expect("lines are kept in every direction");
+        let (start, end) = (lines.position(from), lines.position(to));
+        lines.runs.entry(lines.key(from)).or_default().insert(Interval::new(start.min(end), start.max(end)));
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.lines.iter().any(|l| l.contains(p))
+    }
+
+    // each position is only counted in the first direction with a run through it
+    pub fn len(&self) -> usize {
+        let mut len = 0;
+        for (i, lines) in self.lines.iter().enumerate() {
+            for (key, runs) in &lines.runs {
+                for run in runs.intervals() {
+                    let counted: BTreeSet<isize> = self.lines[..i].iter().flat_map(|earlier| earlier.crossings(lines, *key, run)).collect();
+                    len += run.len() - counted.len();
+                }
+            }
+        }
+        len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(|l| l.runs.is_empty())
+    }
+}
+
+// A rope which steps one cell at a time only until it moves rigidly, when every knot moves just as the head did. As
+// the rules only depend on where the knots are relative to each other, it then keeps doing so, and the rest of a move
+// along a row, column or diagonal is made at once.
+pub struct BulkSimulator<'a> {
+    knots: Vec<Point>,
+    rule: &'a dyn Follow,
+    trails: Vec<Trail>
+}
+
+impl<'a> BulkSimulator<'a> {
+    pub fn new(length: usize, rule: &'a dyn Follow) -> Self {
+        let mut trails: Vec<Trail> = (0..length).map(|_| Trail::default()).collect();
+        for trail in trails.iter_mut() {
+            trail.visit(Point::ORIGIN);
+        }
+        BulkSimulator { knots: vec![Point::ORIGIN; length], rule, trails }
+    }
+
+    pub fn knots(&self) -> &[Point] {
+        &self.knots
+    }
+
+    // moves the head count steps, which are made in bulk once the rope is rigid
+    pub fn advance(&mut self, delta: Point, count: usize) {
+        let mut remaining = count;
+        while remaining > 0 {
+            let before = self.knots.clone();
+            self.knots[0] += delta;
+            self.trails[0].visit(self.knots[0]);
+            for i in 1..self.knots.len() {
+                let moved = self.rule.follow(self.knots[i - 1], self.knots[i]);
+                if moved != self.knots[i] {
+                    self.knots[i] = moved;
+                    self.trails[i].visit(moved);
+                }
+            }
+            remaining -= 1;
+            let rigid = self.knots.iter().zip(&before).all(|(after, before)| *after == *before + delta);
+            if rigid && remaining > 0 {
+                for (knot, trail) in self.knots.iter_mut().zip(self.trails.iter_mut()) {
+                    let end = *knot + delta * remaining as isize;
+                    trail.sweep(*knot, end);
+                    *knot = end;
+                }
+                remaining = 0;
+            }
+        }
+    }
+
+    pub fn trail(&self, knot: usize) -> &Trail {
+        &self.trails[knot]
+    }
+
+    pub fn visited(&self, knot: usize) -> usize {
+        self.trails[knot].len()
+    }
+}
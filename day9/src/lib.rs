@@ -0,0 +1,207 @@
+mod bulk;
+mod rope;
+
+use aoc_common::{ParseError, Point, Solver};
+use aoc_common::input::{parse_lines, parse_number};
+use std::fmt;
+use std::str::FromStr;
+pub use bulk::{BulkSimulator, Trail};
+pub use rope::{parse_rule, Follow, Simulator, Slack, Taut};
+
+// each way the head can move, with y growing downwards
+const DIRECTIONS: [(&str, Point); 8] = [
+    ("U", Point { x: 0, y: -1 }),
+    ("UR", Point { x: 1, y: -1 }),
+    ("R", Point { x: 1, y: 0 }),
+    ("DR", Point { x: 1, y: 1 }),
+    ("D", Point { x: 0, y: 1 }),
+    ("DL", Point { x: -1, y: 1 }),
+    ("L", Point { x: -1, y: 0 }),
+    ("UL", Point { x: -1, y: -1 })
+];
+
+pub struct Instruction {
+    step: Point,
+    count: usize
+}
+
+pub struct Day9 {
+    pub rule: Box<dyn Follow>,
+    // shows the part 2 rope after every step of each instruction in --details
+    pub frames: bool,
+    // the knot of the part 2 rope whose visits --details shows, from 0 for the head
+    pub heatmap: Option<usize>,
+    // makes long moves all at once where it can, for inputs too big to step through
+    pub bulk: bool
+}
+
+impl Default for Day9 {
+    fn default() -> Self {
+        Day9 { rule: Box::new(Slack { max: 1 }), frames: false, heatmap: None, bulk: false }
+    }
+}
+
+impl Day9 {
+    pub fn simulate(&self, instructions: &[Instruction], length: usize) -> Simulator<'_> {
+        let mut simulator = Simulator::new(length, self.rule.as_ref());
+        for instruction in instructions {
+            for _ in 0..instruction.count {
+                simulator.step(instruction.step);
+            }
+        }
+        simulator
+    }
+
+    pub fn simulate_in_bulk(&self, instructions: &[Instruction], length: usize) -> BulkSimulator<'_> {
+        let mut simulator = BulkSimulator::new(length, self.rule.as_ref());
+        for instruction in instructions {
+            simulator.advance(instruction.step, instruction.count);
+        }
+        simulator
+    }
+
+    // the positions visited by each knot
+    fn visited(&self, instructions: &[Instruction], length: usize) -> Vec<usize> {
+        if self.bulk {
+            let rope = self.simulate_in_bulk(instructions, length);
+            (0..length).map(|k| rope.visited(k)).collect()
+        } else {
+            let rope = self.simulate(instructions, length);
+            (0..length).map(|k| rope.visited(k)).collect()
+        }
+    }
+}
+
+impl Solver for Day9 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(text)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> String {
+        format!("{}", self.visited(instructions, 2)[1])
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> String {
+        format!("{}", self.visited(instructions, 10)[9])
+    }
+
+    fn details(&self, instructions: &Self::Input) -> Option<String> {
+        let visited: Vec<String> = self.visited(instructions, 10).iter().enumerate()
+            .map(|(k, v)| format!("{} {}", if k == 0 { "H".to_string() } else { k.to_string() }, v))
+            .collect();
+        let mut lines = vec![format!("Positions visited by each knot, with a {} rule: {}", self.rule.name(), visited.join(", "))];
+        if !self.frames && self.heatmap.is_none() {
+            return Some(lines.join("\n"));
+        }
+        let rope = self.simulate(instructions, 10);
+        let bounds = rope.bounds();
+        if self.frames {
+            let mut frames = Simulator::new(10, self.rule.as_ref());
+            for instruction in instructions {
+                lines.push(format!("\n== {} ==", instruction));
+                for _ in 0..instruction.count {
+                    frames.step(instruction.step);
+                    lines.push(String::new());
+                    lines.push(frames.frame(&bounds));
+                }
+            }
+        }
+        if let Some(knot) = self.heatmap {
+            lines.push(format!("\nVisits by knot {}:", knot));
+            lines.push(rope.heatmap(knot, &bounds));
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+// eg. "R 4", or "UL 2" to move diagonally
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = line.split(" ").collect();
+        if parts.len() != 2 {
+            return Err(format!("expected 2 words in \"{}\"", line));
+        }
+        let (_, step) = DIRECTIONS.iter().find(|(name, _)| *name == parts[0]).ok_or(format!("unknown direction \"{}\"", parts[0]))?;
+        Ok(Instruction { step: *step, count: parse_number(parts[1])? })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, _) = DIRECTIONS.iter().find(|(_, step)| *step == self.step).expect("parsed from a direction");
+        write!(f, "{} {}", name, self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input::normalise;
+
+    const EXAMPLE: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day9::default().part1(&input), "13");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::default().parse(&normalise(EXAMPLE)).unwrap();
+        assert_eq!(Day9::default().part2(&input), "1");
+    }
+
+    #[test]
+    fn draws_frames() {
+        let day9 = Day9::default();
+        let input = day9.parse(&normalise(EXAMPLE)).unwrap();
+        let rope = day9.simulate(&input, 2);
+        assert_eq!(rope.frame(&rope.bounds()), "......\n......\n.TH...\n......\ns.....");
+        assert_eq!(rope.heatmap(1, &rope.bounds()), "..11..\n...12.\n.1111.\n....1.\n1111..");
+        let rope = day9.simulate(&input, 10);
+        assert_eq!(rope.knots()[9], Point::ORIGIN);
+        assert_eq!(rope.visited(1), 13);
+        // a frame after each of the 24 steps, below a heading for each of the 8 moves
+        let details = Day9 { frames: true, ..Day9::default() }.details(&input).unwrap();
+        assert_eq!(details.matches("\n\n").count(), 24 + 8);
+        assert!(details.ends_with(&rope.frame(&rope.bounds())));
+    }
+
+    #[test]
+    fn follows_other_rules() {
+        let input = Day9::default().parse("UR 3\nR 2").unwrap();
+        assert_eq!(input.iter().map(|i| i.to_string()).collect::<Vec<_>>(), vec!["UR 3", "R 2"]);
+        let slack = Day9 { rule: parse_rule("slack:2").unwrap(), ..Day9::default() };
+        assert_eq!(slack.simulate(&input, 2).knots(), &[Point::new(5, -3), Point::new(3, -3)]);
+        let taut = Day9 { rule: parse_rule("taut").unwrap(), ..Day9::default() };
+        assert_eq!(taut.simulate(&input, 2).knots(), &[Point::new(5, -3), Point::new(4, -2)]);
+        assert!(parse_rule("slack:0").is_err() && Day9::default().parse("X 2").is_err());
+    }
+
+    #[test]
+    fn moves_in_bulk() {
+        let day9 = Day9::default();
+        for text in [EXAMPLE, "R 10\nU 10\nL 5", "UR 6\nD 7\nL 3\nR 12\nDL 4\nU 9", "R 8\nUL 4\nD 6\nUR 5\nDL 9\nL 3\nDR 7"] {
+            let input = day9.parse(&normalise(text)).unwrap();
+            for length in [2, 10] {
+                let (rope, bulk) = (day9.simulate(&input, length), day9.simulate_in_bulk(&input, length));
+                assert_eq!(rope.knots(), bulk.knots());
+                assert!((0..length).all(|k| rope.visited(k) == bulk.visited(k)));
+            }
+        }
+        let input = day9.parse("R 1000000000\nU 1000000000\nL 5").unwrap();
+        assert_eq!(day9.simulate_in_bulk(&input, 2).visited(1), 2000000003);
+        let trail = day9.simulate_in_bulk(&input, 2);
+        assert!(trail.trail(1).contains(&Point::new(1000000000, -5)) && !trail.trail(1).contains(&Point::new(5, -5)));
+        // diagonally too, crossing back over where the tail went up and to the right
+        let input = day9.parse("UR 1000000000\nL 1000000000\nDR 1000000000").unwrap();
+        let rope = day9.simulate_in_bulk(&input, 2);
+        assert_eq!(rope.knots(), &[Point::new(1000000000, 0), Point::new(1000000000, -1)]);
+        assert_eq!(rope.visited(1), 2999999997);
+    }
+}
\ No newline at end of file
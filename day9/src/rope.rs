@@ -0,0 +1,129 @@
+use aoc_common::{Bounds, Point};
+use std::collections::HashMap;
+
+// How a knot moves after the knot ahead of it has, which only depends on where they are relative to each other
+pub trait Follow {
+    fn name(&self) -> String;
+
+    // where the knot ends up, given where the knot ahead of it now is
+    fn follow(&self, leader: Point, knot: Point) -> Point;
+}
+
+// Stays put until it is more than max cells away in either direction, then steps one cell (diagonally if need be)
+// towards the knot ahead, which with a max of 1 is the puzzle's rule
+pub struct Slack {
+    pub max: isize
+}
+
+impl Follow for Slack {
+    fn name(&self) -> String {
+        format!("slack of {}", self.max)
+    }
+
+    fn follow(&self, leader: Point, knot: Point) -> Point {
+        if leader.chebyshev(&knot) > self.max {
+            knot + (leader - knot).signum()
+        } else {
+            knot
+        }
+    }
+}
+
+// Moves only as far as it must to be touching the knot ahead, so it can end up diagonally behind it
+pub struct Taut;
+
+impl Follow for Taut {
+    fn name(&self) -> String {
+        "taut".to_string()
+    }
+
+    fn follow(&self, leader: Point, knot: Point) -> Point {
+        let diff = leader - knot;
+        leader - Point::new(diff.x.clamp(-1, 1), diff.y.clamp(-1, 1))
+    }
+}
+
+// eg. "slack:2" or "taut"
+pub fn parse_rule(text: &str) -> Result<Box<dyn Follow>, String> {
+    match text {
+        "taut" => Ok(Box::new(Taut)),
+        _ => match text.strip_prefix("slack:").map(|n| n.parse::<isize>()) {
+            Some(Ok(max)) if max > 0 => Ok(Box::new(Slack { max })),
+            _ => Err(format!("expected a rule of slack:N or taut but found \"{}\"", text))
+        }
+    }
+}
+
+// A rope of knots all starting at the origin, pulled along by its head
+pub struct Simulator<'a> {
+    knots: Vec<Point>,
+    rule: &'a dyn Follow,
+    // how many times each knot has arrived at each position, counting the start at the origin
+    visits: Vec<HashMap<Point, usize>>
+}
+
+impl<'a> Simulator<'a> {
+    pub fn new(length: usize, rule: &'a dyn Follow) -> Self {
+        let visits = (0..length).map(|_| HashMap::from([(Point::ORIGIN, 1)])).collect();
+        Simulator { knots: vec![Point::ORIGIN; length], rule, visits }
+    }
+
+    pub fn knots(&self) -> &[Point] {
+        &self.knots
+    }
+
+    // moves the head a single step, which may be diagonal, and each knot after it in turn
+    pub fn step(&mut self, delta: Point) {
+        self.knots[0] += delta;
+        *self.visits[0].entry(self.knots[0]).or_insert(0) += 1;
+        for i in 1..self.knots.len() {
+            let moved = self.rule.follow(self.knots[i - 1], self.knots[i]);
+            if moved != self.knots[i] {
+                self.knots[i] = moved;
+                *self.visits[i].entry(moved).or_insert(0) += 1;
+            }
+        }
+    }
+
+    pub fn visits(&self, knot: usize) -> &HashMap<Point, usize> {
+        &self.visits[knot]
+    }
+
+    pub fn visited(&self, knot: usize) -> usize {
+        self.visits[knot].len()
+    }
+
+    // everywhere any knot has been
+    pub fn bounds(&self) -> Bounds {
+        Bounds::from_points(self.visits.iter().flat_map(|v| v.keys())).expect("every knot starts somewhere")
+    }
+
+    // like the puzzle's diagrams, with H for the head and then T or the number of each knot, 's' for the start and
+    // knots nearer the head drawn over those behind
+    pub fn frame(&self, bounds: &Bounds) -> String {
+        let mut cells: HashMap<Point, char> = HashMap::from([(Point::ORIGIN, 's')]);
+        for (i, knot) in self.knots.iter().enumerate().rev() {
+            let label = match i {
+                0 => 'H',
+                _ if self.knots.len() == 2 => 'T',
+                _ => char::from_digit(i as u32, 36).unwrap_or('#')
+            };
+            cells.insert(*knot, label);
+        }
+        render(bounds, |p| cells.get(&p).copied().unwrap_or('.'))
+    }
+
+    // how many times a knot arrived at each position, as a digit or '+' for ten or more
+    pub fn heatmap(&self, knot: usize, bounds: &Bounds) -> String {
+        render(bounds, |p| match self.visits[knot].get(&p) {
+            None => '.',
+            Some(count) => char::from_digit(*count as u32, 10).unwrap_or('+')
+        })
+    }
+}
+
+// rows from the top, which is the furthest up
+fn render<F: Fn(Point) -> char>(bounds: &Bounds, cell: F) -> String {
+    (bounds.min.y..=bounds.max.y).map(|y| (bounds.min.x..=bounds.max.x).map(|x| cell(Point::new(x, y))).collect::<String>())
+        .collect::<Vec<String>>().join("\n")
+}
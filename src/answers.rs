@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+// Known-good answers for an input, read from answers.toml alongside it, with a table per input file:
+//   [test]
+//   part1 = 24000
+//   part2 = "45000"
+#[derive(Default)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>
+}
+
+impl Answers {
+    // no answers file (or no table for this input) just means there is nothing to check against
+    pub fn load(filename: &str) -> Result<Self, String> {
+        let path = Path::new(filename);
+        let answers_file = path.with_file_name("answers.toml");
+        let mut answers = Answers::default();
+        if filename == "-" || !answers_file.exists() {
+            return Ok(answers);
+        }
+        let name = answers_file.display().to_string();
+        let text = fs::read_to_string(&answers_file)
+            .map_err(|e| format!("Error reading from {}: {}", name, e))?;
+        let table: Table = text.parse()
+            .map_err(|e| format!("{}: {}", name, e))?;
+        let stem = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        match table.get(&stem) {
+            Some(Value::Table(parts)) => {
+                answers.part1 = expected(parts, "part1", &name)?;
+                answers.part2 = expected(parts, "part2", &name)?;
+            },
+            Some(_) => return Err(format!("{}: expected [{}] to be a table", name, stem)),
+            None => {}
+        }
+        Ok(answers)
+    }
+
+    pub fn part(&self, part: usize) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref()
+        }
+    }
+}
+
+fn expected(parts: &Table, key: &str, name: &str) -> Result<Option<String>, String> {
+    match parts.get(key) {
+        None => Ok(None),
+        // multi-line answers are easiest to write with a trailing newline before the closing quotes
+        Some(Value::String(s)) => Ok(Some(s.trim_end_matches('\n').to_string())),
+        Some(Value::Integer(n)) => Ok(Some(n.to_string())),
+        Some(other) => Err(format!("{}: expected {} to be a string or integer but found {}", name, key, other.type_str()))
+    }
+}
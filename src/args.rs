@@ -0,0 +1,501 @@
+use crate::bench;
+use std::str::FromStr;
+
+pub const DAYS: usize = 25;
+// day options which don't change the answers (mostly only what --details shows), so they can still be checked
+const DETAILS_ONLY: [&str; 10] = ["--opponent", "--games", "--step", "--query", "--scenic", "--observer", "--slope", "--frames", "--heatmap", "--bulk"];
+pub const USAGE: &str = "Usage: advent2022 run [--day N] [--part 1|2] [--format text|json] [--details] [--input File] [Day options]
+  Runs every day (or just --day N) against --input, which defaults to dayN/input.txt, or - for stdin
+  Answers are checked against answers.toml next to the input file, eg. its [test] table for test.txt,
+  unless a day option changes them, and any part without an expected answer there is reported
+  With --format json, each day is printed as a line of json instead
+  With --details, anything else a day can show about its input (eg. statistics) is printed after the answers
+Usage: advent2022 bench [--day N] [--part 1|2] [--runs N] [--history File] [--threshold Percent] [--input File] [Day options]
+  Times parsing and each part (the best of --runs, default 1), appending the results to --history (default bench.json)
+  Any time more than --threshold (default 10) percent slower than the last benchmark of that input and those day
+  options is a regression
+Day options, which are only valid along with that --day:
+  Day 1:  --top N (default 3) the elves whose calories are totalled in part 2
+  Day 2:  --rules rps|rpsls (default rps) rock paper scissors, or with lizard (E/Z) and spock (D/Y) too,
+            where part 2 can only read X/Y/Z as outcomes
+          --opponent W,W,.. (default all equal) the relative chance of each shape in --details simulations
+          --games N (default 1000) the games simulated for --details
+  Day 3:  --compartments N (default 2) the equally sized compartments of each rucksack
+          --group-size N (default 3) the rucksacks in each group sharing a badge
+  Day 5:  --step N (default all) the moves made before --details shows the stacks
+          --crane 9000|9001|limited:N|bottom (default 9000 for part 1, 9001 for part 2) the crane used for both parts
+  Day 7:  --query Query (default \"du -h --max-depth 1\") shown by --details, and can be given more than once:
+            tree [Path]
+            du [-h] [--max-depth N] [Path]
+            find [Path] [--min-size N] [--max-size N] [--name Pattern] [--type f|d]
+          --disk-size N (default 70000000) the size of the disk
+          --required N (default 30000000) the free space needed in part 2, which --details plans a cleanup for
+  Day 8:  --scenic N (default 5) the most scenic trees listed by --details
+          --observer X,Y[,Height] (default none) someone whose view along the 8 compass directions --details shows,
+            with their eyes at the height of the tree there unless Height is given
+          --slope DX,DY (default none) another line the observer looks both ways along, and can be given more than once
+  Day 9:  --follow slack:N|taut (default slack:1) how each knot follows the one ahead: once more than N cells away,
+            or always to touch it
+          --frames shows the part 2 rope after every step of every move in --details
+          --heatmap K (default none) shows how often knot K (0 to 9, from the head) of the part 2 rope reached each
+            position in --details
+          --bulk makes each move all at once after the rope stops changing shape, for huge moves, and can't be used with
+            --frames or --heatmap
+  Day 15: --rows Y (default 2000000) the row checked for beacons in part 1
+          --search-min X --search-max X (default 0 to 4000000) the columns searched in part 2
+          --search-size Y (default 4000000) the rows searched in part 2, from 0
+  Day 17: --rocks N (default 2022 for part 1, 1000000000000 for part 2) the rocks dropped
+  Day 19: --blueprint N (default all) the only blueprint to simulate
+          --minutes N (default 24 for part 1, 32 for part 2) the time available
+  Day 23: --rounds N (default 10) the rounds simulated in part 1
+The input can also be given as the last argument, without --input";
+
+pub enum Command {
+    Run,
+    Bench
+}
+
+#[derive(PartialEq)]
+pub enum Format {
+    Text,
+    Json
+}
+
+pub struct Options {
+    pub command: Command,
+    pub day: Option<usize>,
+    pub part: Option<usize>,
+    pub format: Format,
+    pub details: bool,
+    pub check_answers: bool,
+    pub filename: Option<String>,
+    pub bench: bench::Settings,
+    pub puzzle: Puzzle
+}
+
+// Overrides for the numbers built into some puzzles, which differ between the examples and the real input, grouped by
+// day in the same order as USAGE
+#[derive(Default)]
+pub struct Puzzle {
+    top: Option<usize>,
+    rules: Option<String>,
+    opponent: Option<Vec<f64>>,
+    games: Option<usize>,
+    compartments: Option<usize>,
+    group_size: Option<usize>,
+    step: Option<usize>,
+    crane: Option<String>,
+    queries: Vec<String>,
+    disk_size: Option<usize>,
+    required: Option<usize>,
+    scenic: Option<usize>,
+    observer: Option<String>,
+    slopes: Vec<String>,
+    follow: Option<String>,
+    frames: bool,
+    heatmap: Option<usize>,
+    bulk: bool,
+    rows: Option<isize>,
+    search_min: Option<isize>,
+    search_max: Option<isize>,
+    search_size: Option<isize>,
+    rocks: Option<usize>,
+    blueprint: Option<usize>,
+    minutes: Option<usize>,
+    rounds: Option<usize>
+}
+
+impl Puzzle {
+    pub fn day1(&self) -> day1::Day1 {
+        let default = day1::Day1::default();
+        day1::Day1 {
+            top: self.top.unwrap_or(default.top)
+        }
+    }
+
+    pub fn day2(&self) -> day2::Day2 {
+        let default = day2::Day2::default();
+        day2::Day2 {
+            rules: match self.rules.as_deref() {
+                Some("rpsls") => day2::Rules::rock_paper_scissors_lizard_spock(),
+                _ => default.rules
+            },
+            opponent: self.opponent.clone(),
+            games: self.games.unwrap_or(default.games)
+        }
+    }
+
+    pub fn day3(&self) -> day3::Day3 {
+        let default = day3::Day3::default();
+        day3::Day3 {
+            compartments: self.compartments.unwrap_or(default.compartments),
+            group_size: self.group_size.unwrap_or(default.group_size)
+        }
+    }
+
+    pub fn day5(&self) -> day5::Day5 {
+        day5::Day5 {
+            step: self.step,
+            crane: self.crane.as_deref().map(|c| day5::parse_crane(c).expect("checked by parse_args"))
+        }
+    }
+
+    pub fn day7(&self) -> day7::Day7 {
+        let default = day7::Day7::default();
+        day7::Day7 {
+            queries: self.queries.iter().map(|q| q.parse().expect("checked by parse_args")).collect(),
+            disk_size: self.disk_size.unwrap_or(default.disk_size),
+            required: self.required.unwrap_or(default.required)
+        }
+    }
+
+    pub fn day8(&self) -> day8::Day8 {
+        let default = day8::Day8::default();
+        day8::Day8 {
+            best: self.scenic.unwrap_or(default.best),
+            observer: self.observer.as_ref().map(|o| o.parse().expect("checked by parse_args")),
+            slopes: self.slopes.iter().map(|s| day8::parse_slope(s).expect("checked by parse_args")).collect()
+        }
+    }
+
+    pub fn day9(&self) -> day9::Day9 {
+        let default = day9::Day9::default();
+        day9::Day9 {
+            rule: self.follow.as_deref().map(|f| day9::parse_rule(f).expect("checked by parse_args")).unwrap_or(default.rule),
+            frames: self.frames,
+            heatmap: self.heatmap,
+            bulk: self.bulk
+        }
+    }
+
+    pub fn day15(&self) -> day15::Day15 {
+        let default = day15::Day15::default();
+        day15::Day15 {
+            row: self.rows.unwrap_or(default.row),
+            search_min: self.search_min.unwrap_or(default.search_min),
+            search_max: self.search_max.unwrap_or(default.search_max),
+            search_size: self.search_size.unwrap_or(default.search_size)
+        }
+    }
+
+    pub fn day17(&self) -> day17::Day17 {
+        let default = day17::Day17::default();
+        day17::Day17 {
+            rocks_part1: self.rocks.unwrap_or(default.rocks_part1),
+            rocks_part2: self.rocks.unwrap_or(default.rocks_part2)
+        }
+    }
+
+    pub fn day19(&self) -> day19::Day19 {
+        day19::Day19 {
+            blueprint: self.blueprint,
+            minutes: self.minutes
+        }
+    }
+
+    pub fn day23(&self) -> day23::Day23 {
+        let default = day23::Day23::default();
+        day23::Day23 {
+            rounds: self.rounds.unwrap_or(default.rounds)
+        }
+    }
+}
+
+pub fn wants_help(args: &[String]) -> bool {
+    args.iter().any(|a| a == "--help" || a == "-h") || args.first().is_some_and(|a| a == "help")
+}
+
+pub fn parse_args(args: &[String]) -> Result<Options, String> {
+    let command = match args.first().map(|s| s.as_str()) {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        _ => return Err("Expected a command: run or bench".to_string())
+    };
+    let mut options = Options { command, day: None, part: None, format: Format::Text, details: false, check_answers: true, filename: None, bench: bench::Settings::default(), puzzle: Puzzle::default() };
+    // day options given, to check against --day once it is known
+    let mut day_options: Vec<(&str, usize)> = Vec::new();
+    let mut i = 1;
+    while i < args.len() {
+        let start = i;
+        let known_day_options = day_options.len();
+        match args[i].as_str() {
+            "--day" => {
+                i += 1;
+                let day: usize = parse_value(args, i, "--day")?;
+                if !(1..=DAYS).contains(&day) {
+                    return Err(format!("Day must be between 1 and {}: {}", DAYS, day));
+                }
+                options.day = Some(day);
+            },
+            "--part" => {
+                i += 1;
+                let part: usize = parse_value(args, i, "--part")?;
+                if part != 1 && part != 2 {
+                    return Err(format!("Part must be 1 or 2: {}", part));
+                }
+                options.part = Some(part);
+            },
+            "--input" => {
+                i += 1;
+                let filename: String = parse_value(args, i, "--input")?;
+                set_filename(&mut options, filename)?;
+            },
+            "--format" | "--details" if !matches!(options.command, Command::Run) => {
+                return Err(format!("{} is only valid for run", args[i]));
+            },
+            "--format" => {
+                i += 1;
+                let format: String = parse_value(args, i, "--format")?;
+                options.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("Format must be text or json: {}", format))
+                };
+            },
+            "--details" => {
+                options.details = true;
+            },
+            "--runs" | "--history" | "--threshold" if !matches!(options.command, Command::Bench) => {
+                return Err(format!("{} is only valid for bench", args[i]));
+            },
+            "--runs" => {
+                i += 1;
+                options.bench.runs = parse_value(args, i, "--runs")?;
+                if options.bench.runs == 0 {
+                    return Err("Runs must be at least 1".to_string());
+                }
+            },
+            "--history" => {
+                i += 1;
+                options.bench.history = parse_value(args, i, "--history")?;
+            },
+            "--threshold" => {
+                i += 1;
+                options.bench.threshold = parse_value(args, i, "--threshold")?;
+            },
+            "--top" => {
+                i += 1;
+                let top: usize = parse_value(args, i, "--top")?;
+                if top == 0 {
+                    return Err("Top must be at least 1".to_string());
+                }
+                options.puzzle.top = Some(top);
+                day_options.push(("--top", 1));
+            },
+            "--rules" => {
+                i += 1;
+                let rules: String = parse_value(args, i, "--rules")?;
+                if rules != "rps" && rules != "rpsls" {
+                    return Err(format!("Rules must be rps or rpsls: {}", rules));
+                }
+                options.puzzle.rules = Some(rules);
+                day_options.push(("--rules", 2));
+            },
+            "--opponent" => {
+                i += 1;
+                let weights: String = parse_value(args, i, "--opponent")?;
+                let weights: Vec<f64> = weights.split(',').map(|w| w.parse().map_err(|_| format!("Invalid value for --opponent: {}", weights))).collect::<Result<_, _>>()?;
+                // NaN and infinite weights parse as f64, but can't be sampled from
+                let total: f64 = weights.iter().sum();
+                if weights.iter().any(|w| !w.is_finite() || *w < 0.0) || !total.is_finite() || total <= 0.0 {
+                    return Err(format!("Invalid value for --opponent: {} (weights must be finite and not negative, and at least one must be positive)", args[i]));
+                }
+                options.puzzle.opponent = Some(weights);
+                day_options.push(("--opponent", 2));
+            },
+            "--games" => {
+                i += 1;
+                let games: usize = parse_value(args, i, "--games")?;
+                if games == 0 {
+                    return Err("Games must be at least 1".to_string());
+                }
+                options.puzzle.games = Some(games);
+                day_options.push(("--games", 2));
+            },
+            "--compartments" => {
+                i += 1;
+                let compartments: usize = parse_value(args, i, "--compartments")?;
+                if compartments == 0 {
+                    return Err("Compartments must be at least 1".to_string());
+                }
+                options.puzzle.compartments = Some(compartments);
+                day_options.push(("--compartments", 3));
+            },
+            "--group-size" => {
+                i += 1;
+                let group_size: usize = parse_value(args, i, "--group-size")?;
+                if group_size == 0 {
+                    return Err("Group size must be at least 1".to_string());
+                }
+                options.puzzle.group_size = Some(group_size);
+                day_options.push(("--group-size", 3));
+            },
+            "--step" => {
+                i += 1;
+                options.puzzle.step = Some(parse_value(args, i, "--step")?);
+                day_options.push(("--step", 5));
+            },
+            "--crane" => {
+                i += 1;
+                let crane: String = parse_value(args, i, "--crane")?;
+                day5::parse_crane(&crane).map_err(|_| format!("Crane must be 9000, 9001, limited:N or bottom: {}", crane))?;
+                options.puzzle.crane = Some(crane);
+                day_options.push(("--crane", 5));
+            },
+            "--query" => {
+                i += 1;
+                let query: String = parse_value(args, i, "--query")?;
+                query.parse::<day7::Query>().map_err(|e| format!("Invalid value for --query: {}", e))?;
+                options.puzzle.queries.push(query);
+                day_options.push(("--query", 7));
+            },
+            "--disk-size" => {
+                i += 1;
+                options.puzzle.disk_size = Some(parse_value(args, i, "--disk-size")?);
+                day_options.push(("--disk-size", 7));
+            },
+            "--required" => {
+                i += 1;
+                options.puzzle.required = Some(parse_value(args, i, "--required")?);
+                day_options.push(("--required", 7));
+            },
+            "--scenic" => {
+                i += 1;
+                options.puzzle.scenic = Some(parse_value(args, i, "--scenic")?);
+                day_options.push(("--scenic", 8));
+            },
+            "--observer" => {
+                i += 1;
+                let observer: String = parse_value(args, i, "--observer")?;
+                observer.parse::<day8::Observer>().map_err(|e| format!("Invalid value for --observer: {}", e))?;
+                options.puzzle.observer = Some(observer);
+                day_options.push(("--observer", 8));
+            },
+            "--slope" => {
+                i += 1;
+                let slope: String = parse_value(args, i, "--slope")?;
+                day8::parse_slope(&slope).map_err(|e| format!("Invalid value for --slope: {}", e))?;
+                options.puzzle.slopes.push(slope);
+                day_options.push(("--slope", 8));
+            },
+            "--follow" => {
+                i += 1;
+                let follow: String = parse_value(args, i, "--follow")?;
+                day9::parse_rule(&follow).map_err(|_| format!("Follow must be slack:N or taut: {}", follow))?;
+                options.puzzle.follow = Some(follow);
+                day_options.push(("--follow", 9));
+            },
+            "--frames" => {
+                options.puzzle.frames = true;
+                day_options.push(("--frames", 9));
+            },
+            "--heatmap" => {
+                i += 1;
+                let knot: usize = parse_value(args, i, "--heatmap")?;
+                if knot > 9 {
+                    return Err("The part 2 rope has knots 0 to 9".to_string());
+                }
+                options.puzzle.heatmap = Some(knot);
+                day_options.push(("--heatmap", 9));
+            },
+            "--bulk" => {
+                options.puzzle.bulk = true;
+                day_options.push(("--bulk", 9));
+            },
+            "--rows" => {
+                i += 1;
+                options.puzzle.rows = Some(parse_value(args, i, "--rows")?);
+                day_options.push(("--rows", 15));
+            },
+            "--search-min" => {
+                i += 1;
+                options.puzzle.search_min = Some(parse_value(args, i, "--search-min")?);
+                day_options.push(("--search-min", 15));
+            },
+            "--search-max" => {
+                i += 1;
+                options.puzzle.search_max = Some(parse_value(args, i, "--search-max")?);
+                day_options.push(("--search-max", 15));
+            },
+            "--search-size" => {
+                i += 1;
+                options.puzzle.search_size = Some(parse_value(args, i, "--search-size")?);
+                day_options.push(("--search-size", 15));
+            },
+            "--rocks" => {
+                i += 1;
+                options.puzzle.rocks = Some(parse_value(args, i, "--rocks")?);
+                day_options.push(("--rocks", 17));
+            },
+            "--blueprint" => {
+                i += 1;
+                let blueprint: usize = parse_value(args, i, "--blueprint")?;
+                if blueprint == 0 {
+                    return Err("Blueprints are numbered from 1".to_string());
+                }
+                options.puzzle.blueprint = Some(blueprint);
+                day_options.push(("--blueprint", 19));
+            },
+            "--minutes" => {
+                i += 1;
+                let minutes: usize = parse_value(args, i, "--minutes")?;
+                if minutes == 0 {
+                    return Err("Minutes must be at least 1".to_string());
+                }
+                options.puzzle.minutes = Some(minutes);
+                day_options.push(("--minutes", 19));
+            },
+            "--rounds" => {
+                i += 1;
+                options.puzzle.rounds = Some(parse_value(args, i, "--rounds")?);
+                day_options.push(("--rounds", 23));
+            },
+            flag if flag.starts_with("--") => return Err(format!("Unknown option: {}", flag)),
+            filename => set_filename(&mut options, filename.to_string())?
+        }
+        // options which change the answers also change the timings, so benchmarks are only compared when they match
+        if day_options.get(known_day_options).is_some_and(|(flag, _)| !DETAILS_ONLY.contains(flag)) {
+            options.bench.options.push(args[start..(i+1)].join(" "));
+        }
+        i += 1;
+    }
+    if options.filename.is_some() && options.day.is_none() {
+        return Err("An input file can only be given along with --day".to_string());
+    }
+    if let Some((flag, day)) = day_options.iter().find(|(_, day)| options.day != Some(*day)) {
+        return Err(format!("{} is only valid along with --day {}", flag, day));
+    }
+    options.check_answers = day_options.iter().all(|(flag, _)| DETAILS_ONLY.contains(flag));
+    options.bench.options.sort();
+    if let Some(weights) = &options.puzzle.opponent {
+        let shapes = options.puzzle.day2().rules.shapes.len();
+        if weights.len() != shapes {
+            return Err(format!("Expected {} opponent weights, one for each shape, but found {}", shapes, weights.len()));
+        }
+    }
+    if !options.puzzle.slopes.is_empty() && options.puzzle.observer.is_none() {
+        return Err("--slope is only valid along with --observer".to_string());
+    }
+    if options.puzzle.bulk && (options.puzzle.frames || options.puzzle.heatmap.is_some()) {
+        return Err("--bulk can't be used with --frames or --heatmap".to_string());
+    }
+    if let (Some(min), Some(max)) = (options.puzzle.search_min, options.puzzle.search_max) {
+        if min > max {
+            return Err(format!("--search-min {} is more than --search-max {}", min, max));
+        }
+    }
+    Ok(options)
+}
+
+fn set_filename(options: &mut Options, filename: String) -> Result<(), String> {
+    if options.filename.is_some() {
+        return Err(format!("Unexpected argument: {}", filename));
+    }
+    options.filename = Some(filename);
+    Ok(())
+}
+
+fn parse_value<T: FromStr>(args: &[String], i: usize, flag: &str) -> Result<T, String> {
+    let value = args.get(i).ok_or(format!("Missing value for {}", flag))?;
+    value.parse().map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
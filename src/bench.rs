@@ -0,0 +1,146 @@
+use aoc_common::Solver;
+use aoc_common::input::read_input;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// slowdowns smaller than this are timer noise rather than regressions
+const NOISE_FLOOR_MS: f64 = 1.0;
+
+pub struct Settings {
+    pub runs: usize,
+    pub history: String,
+    pub threshold: f64,
+    pub options: Vec<String> // the day options which change the answers, eg. "--rocks 100"
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            runs: 1,
+            history: "bench.json".to_string(),
+            threshold: 10.0,
+            options: Vec::new()
+        }
+    }
+}
+
+// one benchmark of one input, as stored in the history file
+#[derive(Serialize, Deserialize)]
+struct Record {
+    timestamp: u64,
+    day: usize,
+    input: String,
+    #[serde(default)]
+    options: Vec<String>,
+    runs: usize,
+    parse_ms: f64,
+    part1_ms: Option<f64>,
+    part2_ms: Option<f64>
+}
+
+// the name, current time and how to find the previous time of one thing being benchmarked
+type Timing = (&'static str, Option<f64>, fn(&Record) -> Option<f64>);
+
+pub fn bench<S: Solver>(solver: S, day: usize, part: Option<usize>, filename: &str, settings: &Settings) -> Result<(), String> {
+    let text = read_input(filename)
+        .map_err(|e| format!("Error reading from {}: {}", filename, e))?;
+    let mut history = load_history(&settings.history)?;
+    let (input, parse) = best_of(settings.runs, || solver.parse(&text));
+    let input = input.map_err(|e| format!("{}:{}", filename, e))?;
+    let part1 = if part != Some(2) {
+        Some(best_of(settings.runs, || solver.part1(&input)).1)
+    } else {
+        None
+    };
+    let part2 = if part != Some(1) {
+        Some(best_of(settings.runs, || solver.part2(&input)).1)
+    } else {
+        None
+    };
+    let record = Record {
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+        day,
+        input: filename.to_string(),
+        options: settings.options.clone(),
+        runs: settings.runs,
+        parse_ms: millis(parse),
+        part1_ms: part1.map(millis),
+        part2_ms: part2.map(millis)
+    };
+    let options: String = settings.options.iter().map(|o| format!(" {}", o)).collect();
+    println!("Day {}: {}{} (best of {})", day, filename, options, settings.runs);
+    let mut regressed = Vec::new();
+    let timings: [Timing; 3] = [
+        ("Parse", Some(record.parse_ms), |r| Some(r.parse_ms)),
+        ("Part 1", record.part1_ms, |r| r.part1_ms),
+        ("Part 2", record.part2_ms, |r| r.part2_ms)
+    ];
+    for (name, current, get) in timings {
+        if let Some(current) = current {
+            // compare against the most recent benchmark of this input and options which timed the same thing
+            let previous = history.iter().rev().filter(|r| r.day == day && r.input == filename && r.options == record.options).find_map(get);
+            if report(name, current, previous, settings.threshold) {
+                regressed.push(name);
+            }
+        }
+    }
+    history.push(record);
+    save_history(&settings.history, &history)?;
+    if regressed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{}: {} regressed by more than {}%", filename, regressed.join(" & "), settings.threshold))
+    }
+}
+
+// runs f the given number of times, returning the last result and the fastest time
+fn best_of<T, F: FnMut() -> T>(runs: usize, mut f: F) -> (T, Duration) {
+    let mut best = Duration::MAX;
+    let mut result = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        result = Some(f());
+        best = best.min(start.elapsed());
+    }
+    (result.expect("ran at least once"), best)
+}
+
+// returns true if this timing is a regression on the previous one
+fn report(name: &str, current: f64, previous: Option<f64>, threshold: f64) -> bool {
+    match previous {
+        None => {
+            println!("{}: {:?}", name, duration(current));
+            false
+        },
+        Some(previous) => {
+            let change = if previous > 0.0 { 100.0 * (current - previous) / previous } else { 0.0 };
+            let regressed = change > threshold && current - previous > NOISE_FLOOR_MS;
+            println!("{}: {:?} (was {:?}, {:+.0}%){}", name, duration(current), duration(previous), change, if regressed { " REGRESSION" } else { "" });
+            regressed
+        }
+    }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn duration(ms: f64) -> Duration {
+    Duration::from_secs_f64(ms / 1000.0)
+}
+
+fn load_history(filename: &str) -> Result<Vec<Record>, String> {
+    if !Path::new(filename).exists() {
+        return Ok(Vec::new());
+    }
+    let text = fs::read_to_string(filename)
+        .map_err(|e| format!("Error reading from {}: {}", filename, e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", filename, e))
+}
+
+fn save_history(filename: &str, history: &[Record]) -> Result<(), String> {
+    let text = serde_json::to_string_pretty(history).map_err(|e| format!("{}: {}", filename, e))?;
+    fs::write(filename, text).map_err(|e| format!("Error writing to {}: {}", filename, e))
+}
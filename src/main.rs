@@ -0,0 +1,173 @@
+mod answers;
+mod args;
+mod bench;
+mod report;
+
+use answers::Answers;
+use args::{parse_args, wants_help, Command, Format, Options, DAYS, USAGE};
+use report::Report;
+use aoc_common::Solver;
+use aoc_common::input::open_input;
+use std::env;
+use std::process;
+use std::time::{Duration, Instant};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if wants_help(&args[1..]) {
+        println!("{}", USAGE);
+        return;
+    }
+    let options = match parse_args(&args[1..]) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    let days: Vec<usize> = match options.day {
+        Some(day) => vec![day],
+        None => (1..(DAYS+1)).collect()
+    };
+    let mut failed = false;
+    for day in days {
+        let filename = match &options.filename {
+            Some(filename) => filename.clone(),
+            None => format!("day{}/input.txt", day)
+        };
+        if let Err(message) = run_day(day, &options, &filename) {
+            eprintln!("{}", message);
+            failed = true;
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_day(day: usize, options: &Options, filename: &str) -> Result<(), String> {
+    match day {
+        1 => execute(options.puzzle.day1(), day, options, filename),
+        2 => execute(options.puzzle.day2(), day, options, filename),
+        3 => execute(options.puzzle.day3(), day, options, filename),
+        4 => execute(day4::Day4, day, options, filename),
+        5 => execute(options.puzzle.day5(), day, options, filename),
+        6 => execute(day6::Day6, day, options, filename),
+        7 => execute(options.puzzle.day7(), day, options, filename),
+        8 => execute(options.puzzle.day8(), day, options, filename),
+        9 => execute(options.puzzle.day9(), day, options, filename),
+        10 => execute(day10::Day10, day, options, filename),
+        11 => execute(day11::Day11, day, options, filename),
+        12 => execute(day12::Day12, day, options, filename),
+        13 => execute(day13::Day13, day, options, filename),
+        14 => execute(day14::Day14, day, options, filename),
+        15 => execute(options.puzzle.day15(), day, options, filename),
+        16 => execute(day16::Day16, day, options, filename),
+        17 => execute(options.puzzle.day17(), day, options, filename),
+        18 => execute(day18::Day18, day, options, filename),
+        19 => execute(options.puzzle.day19(), day, options, filename),
+        20 => execute(day20::Day20, day, options, filename),
+        21 => execute(day21::Day21, day, options, filename),
+        22 => execute(day22::Day22, day, options, filename),
+        23 => execute(options.puzzle.day23(), day, options, filename),
+        24 => execute(day24::Day24, day, options, filename),
+        25 => execute(day25::Day25, day, options, filename),
+        _ => Err(format!("Day not found: {}", day))
+    }
+}
+
+fn execute<S: Solver>(solver: S, day: usize, options: &Options, filename: &str) -> Result<(), String> {
+    match options.command {
+        Command::Run => solve(solver, day, options, filename),
+        Command::Bench => bench::bench(solver, day, options.part, filename, &options.bench)
+    }
+}
+
+fn solve<S: Solver>(solver: S, day: usize, options: &Options, filename: &str) -> Result<(), String> {
+    let mut report = Report::new(day, filename);
+    let result = solve_into(&solver, day, options, filename, &mut report);
+    if let Err(message) = &result {
+        report.diagnostics.push(message.clone());
+    }
+    if options.format == Format::Json {
+        println!("{}", report.to_json());
+    }
+    result
+}
+
+// only prints as it goes in text format, otherwise everything ends up in the report
+fn solve_into<S: Solver>(solver: &S, day: usize, options: &Options, filename: &str, report: &mut Report) -> Result<(), String> {
+    let reader = open_input(filename)
+        .map_err(|e| format!("Error reading from {}: {}", filename, e))?;
+    let answers = if options.check_answers { Answers::load(filename)? } else { Answers::default() };
+    // includes the time spent reading, since some days parse as they read
+    let start = Instant::now();
+    let input = solver.parse_reader(reader)
+        .map_err(|e| format!("Error reading from {}: {}", filename, e))?
+        .map_err(|e| format!("{}:{}", filename, e))?;
+    report.parsed(start.elapsed());
+    if options.format == Format::Text {
+        println!("Day {}: {} (parsed in {:?})", day, filename, start.elapsed());
+    }
+    for warning in solver.warnings(&input) {
+        let message = format!("Warning: {}:{}", filename, warning);
+        if options.format == Format::Text {
+            eprintln!("{}", message);
+        }
+        report.diagnostics.push(message);
+    }
+    let mut failed = Vec::new();
+    for part in [1, 2] {
+        if options.part.is_some_and(|p| p != part) {
+            continue;
+        }
+        let start = Instant::now();
+        let answer = if part == 1 { solver.part1(&input) } else { solver.part2(&input) };
+        let elapsed = start.elapsed();
+        report.answered(part, &answer, elapsed);
+        let expected = answers.part(part);
+        // only worth mentioning when it could have been checked
+        let unchecked = options.check_answers && expected.is_none();
+        if unchecked {
+            report.diagnostics.push(format!("part {} has no expected answer in answers.toml", part));
+        }
+        if options.format == Format::Text {
+            print_answer(part, &answer, elapsed, expected, unchecked);
+        }
+        if let Some(expected) = expected.filter(|e| *e != answer) {
+            report.diagnostics.push(format!("part {} expected {:?} but found {:?}", part, expected, answer));
+            failed.push(part.to_string());
+        }
+    }
+    if options.details {
+        report.details = solver.details(&input);
+        if let (Format::Text, Some(details)) = (&options.format, &report.details) {
+            println!("Details:\n{}", details);
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{}: part {} did not match answers.toml", filename, failed.join(" & ")))
+    }
+}
+
+fn print_answer(part: usize, answer: &str, elapsed: Duration, expected: Option<&str>, unchecked: bool) {
+    let status = match expected {
+        None if unchecked => " (no expected answer)",
+        None => "",
+        Some(e) if e == answer => " PASS",
+        Some(_) => " FAIL"
+    };
+    if answer.contains('\n') {
+        println!("Part {} ({:?}){}:\n{}", part, elapsed, status, answer);
+    } else {
+        println!("Part {}: {} ({:?}){}", part, answer, elapsed, status);
+    }
+    match expected {
+        Some(e) if e != answer && e.contains('\n') => println!("Expected:\n{}", e),
+        Some(e) if e != answer => println!("Expected: {}", e),
+        _ => {}
+    }
+}
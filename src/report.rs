@@ -0,0 +1,57 @@
+use serde::Serialize;
+use std::time::Duration;
+
+// Everything found while running one day against one input, printed as a line of json by --format json
+#[derive(Serialize)]
+pub struct Report {
+    pub day: usize,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub details: Option<String>,
+    pub timings: Timings,
+    pub diagnostics: Vec<String>
+}
+
+#[derive(Serialize, Default)]
+pub struct Timings {
+    pub parse_ms: Option<f64>,
+    pub part1_ms: Option<f64>,
+    pub part2_ms: Option<f64>
+}
+
+impl Report {
+    pub fn new(day: usize, input: &str) -> Self {
+        Report {
+            day,
+            input: input.to_string(),
+            part1: None,
+            part2: None,
+            details: None,
+            timings: Timings::default(),
+            diagnostics: Vec::new()
+        }
+    }
+
+    pub fn parsed(&mut self, elapsed: Duration) {
+        self.timings.parse_ms = Some(millis(elapsed));
+    }
+
+    pub fn answered(&mut self, part: usize, answer: &str, elapsed: Duration) {
+        if part == 1 {
+            self.part1 = Some(answer.to_string());
+            self.timings.part1_ms = Some(millis(elapsed));
+        } else {
+            self.part2 = Some(answer.to_string());
+            self.timings.part2_ms = Some(millis(elapsed));
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("report is always serializable")
+    }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
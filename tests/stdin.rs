@@ -0,0 +1,23 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// runs the real binary, with the input piped to it as for --input -
+fn run_with_stdin(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent2022"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("runs advent2022");
+    child.stdin.take().expect("stdin is piped").write_all(input.as_bytes()).expect("writes stdin");
+    let output = child.wait_with_output().expect("finishes");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).expect("utf8 output")
+}
+
+#[test]
+fn streams_day6_from_stdin() {
+    let output = run_with_stdin(&["run", "--day", "6", "--format", "json", "--input", "-"], "mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\nbvwbjplbgvbhsrlpgdmjqwftvncz\r\n");
+    assert!(output.contains(r#""part1":"7, 5""#), "{}", output);
+    assert!(output.contains(r#""part2":"19, 23""#), "{}", output);
+}